@@ -1,5 +1,7 @@
 // Seed constants for PDA derivation
 pub const POOL_SEED: &[u8] = b"pool";
+pub const POOL_MINT_SEED: &[u8] = b"pool_mint";
 pub const SENDER_SEED: &[u8] = b"sender";
 pub const RECIPIENT_SEED: &[u8] = b"recipient";
 pub const NONCE_SEED: &[u8] = b"nonce";
+pub const UNWRAP_SEED: &[u8] = b"unwrap";
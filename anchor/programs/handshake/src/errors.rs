@@ -91,4 +91,28 @@ pub enum HandshakeError {
 
     #[msg("Only recipient can decline transfer")]
     OnlyRecipientCanDecline,
+
+    #[msg("Token account is not this transfer's refund destination")]
+    InvalidRefundAccount,
+
+    #[msg("Account is not this transfer's rent recipient")]
+    InvalidRentRecipient,
+
+    #[msg("Mint has a Token-2022 extension pools do not support")]
+    UnsupportedMintExtension,
+
+    #[msg("Pool does not escrow native SOL")]
+    NotNativePool,
+
+    #[msg("Mint is already escrowed by this pool")]
+    MintAlreadyInPool,
+
+    #[msg("Pool still has added mints")]
+    PoolHasMints,
+
+    #[msg("Sweeping surplus needs the operator's token account")]
+    MissingSweepAccount,
+
+    #[msg("Pool still holds escrowed funds")]
+    EscrowedFundsRemaining,
 }
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use crate::{state::*, errors::*, constants::*, token_extensions};
+
+/// Add another mint to the pool (operator only)
+///
+/// The mint gets its own vault (the pool's token account for it) and a `PoolMint`
+/// child holding its fee and stats. Transfers in it pass the child to
+/// `create_transfer`, `claim_transfer` and the refund instructions.
+pub fn add_pool_mint(ctx: Context<AddPoolMint>, transfer_fee_bps: u16) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let pool_mint = &mut ctx.accounts.pool_mint;
+
+    // Validate operator
+    require!(
+        ctx.accounts.operator.key() == pool.operator,
+        HandshakeError::Unauthorized
+    );
+
+    // Validate fee configuration
+    require!(
+        transfer_fee_bps <= 10000,
+        HandshakeError::InvalidTransferFee
+    );
+
+    // The pool's own mint is booked on the pool itself
+    require!(
+        ctx.accounts.mint.key() != pool.mint,
+        HandshakeError::MintAlreadyInPool
+    );
+
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info())?;
+
+    pool_mint.version = 1;
+    pool_mint.bump = ctx.bumps.pool_mint;
+    pool_mint.pool = pool.key();
+    pool_mint.mint = ctx.accounts.mint.key();
+    pool_mint.transfer_fee_bps = transfer_fee_bps;
+    pool_mint.total_deposits = 0;
+    pool_mint.total_withdrawals = 0;
+    pool_mint.total_escrowed = 0;
+    pool_mint.collected_fees = 0;
+
+    pool.mint_count = pool
+        .mint_count
+        .checked_add(1)
+        .ok_or(HandshakeError::MathOverflow)?;
+
+    emit!(PoolMintAdded {
+        pool: pool.key(),
+        pool_mint: pool_mint.key(),
+        mint: pool_mint.mint,
+        transfer_fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddPoolMint<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.pool_id.as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The token mint to add
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Child holding the mint's fee and stats - PDA derived from pool and mint
+    #[account(
+        init,
+        payer = operator,
+        space = PoolMint::SPACE,
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub pool_mint: Box<Account<'info, PoolMint>>,
+
+    /// Pool's token account for the mint - anyone can create an ATA, so it may exist
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PoolMintAdded {
+    pub pool: Pubkey,
+    pub pool_mint: Pubkey,
+    pub mint: Pubkey,
+    pub transfer_fee_bps: u16,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::native::*;
+use super::cancel_transfer::TransferCancelled;
+
+/// Cancel an active transfer in a native SOL pool and refund the sender in lamports
+///
+/// Only while the refund still goes to the sender's own wSOL ATA; the transfer's
+/// rent goes to `rent_to` as usual. The sender signs as `payer`.
+pub fn cancel_native_transfer(ctx: Context<CancelNativeTransfer>) -> Result<()> {
+    let refund = &mut ctx.accounts.refund;
+
+    // Validate sender can cancel
+    refund.transfer.validate_sender_can_cancel(refund.payer.key())?;
+
+    // Refund full amount as lamports (NO fee on cancellation)
+    refund.refund()?;
+
+    // Mark transfer as cancelled
+    refund.transfer.mark_as_cancelled()?;
+
+    emit!(TransferCancelled {
+        transfer: refund.transfer.key(),
+        pool: refund.pool.key(),
+        sender: refund.transfer.sender,
+        recipient: refund.transfer.recipient,
+        amount: refund.transfer.amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelNativeTransfer<'info> {
+    /// Sender as `payer` — fronts the unwrap account's rent
+    pub refund: NativeRefund<'info>,
+}
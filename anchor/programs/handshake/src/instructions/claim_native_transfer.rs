@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, TransferChecked, Mint, TokenAccount, TokenInterface,
+};
+use crate::{state::*, errors::*, constants::*};
+use super::claim_transfer::TransferClaimed;
+
+/// Claim an active transfer from a native SOL pool as lamports
+///
+/// The net amount is moved into a temporary wSOL account that is closed straight
+/// back to the recipient, who fronts its rent and gets it back in the same call.
+pub fn claim_native_transfer(ctx: Context<ClaimNativeTransfer>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let transfer = &mut ctx.accounts.transfer;
+
+    // Validate recipient can claim
+    transfer.validate_recipient_can_claim(ctx.accounts.recipient.key())?;
+
+    // Calculate fee
+    let fee = pool.calculate_transfer_fee(transfer.amount);
+    let net_amount = transfer.amount
+        .checked_sub(fee)
+        .ok_or(HandshakeError::CalculationError)?;
+
+    let pool_seeds = &[POOL_SEED, pool.pool_id.as_ref(), &[pool.bump]];
+    let pool_signer_seeds = &[&pool_seeds[..]];
+
+    // Move the net amount into the unwrap account
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.pool_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.unwrap_account.to_account_info(),
+        authority: pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_accounts,
+        pool_signer_seeds,
+    );
+    transfer_checked(cpi_ctx, net_amount, ctx.accounts.mint.decimals)?;
+
+    // Closing a wSOL account releases all of its lamports to the recipient
+    let close_accounts = CloseAccount {
+        account: ctx.accounts.unwrap_account.to_account_info(),
+        destination: ctx.accounts.recipient.to_account_info(),
+        authority: pool.to_account_info(),
+    };
+    let close_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_accounts,
+        pool_signer_seeds,
+    );
+    close_account(close_cpi_ctx)?;
+
+    // Update pool accounting
+    pool.add_withdrawal(transfer.amount)?;
+    if fee > 0 {
+        pool.add_collected_fees(fee)?;
+    }
+    pool.increment_transfers_resolved()?;
+
+    // Mark transfer as claimed (closed to rent_to)
+    transfer.mark_as_claimed()?;
+
+    emit!(TransferClaimed {
+        transfer: transfer.key(),
+        pool: pool.key(),
+        sender: transfer.sender,
+        recipient: transfer.recipient,
+        amount: transfer.amount,
+        fee,
+        net_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimNativeTransfer<'info> {
+    /// Recipient — fronts the unwrap account's rent and receives the lamports
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// The pool this transfer belongs to — must escrow native SOL
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.pool_id.as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_native() @ HandshakeError::NotNativePool
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The mint for validation
+    #[account(
+        constraint = mint.key() == pool.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Pool's wSOL account
+    #[account(
+        mut,
+        associated_token::mint = pool.mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Temporary wSOL account, closed to the recipient before the instruction ends
+    #[account(
+        init,
+        payer = recipient,
+        seeds = [
+            UNWRAP_SEED,
+            transfer.key().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program
+    )]
+    pub unwrap_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Transfer account to claim (closed to rent_to on success)
+    #[account(
+        mut,
+        close = rent_to,
+        constraint = transfer.pool == pool.key()
+    )]
+    pub transfer: Box<Account<'info, SecureTransfer>>,
+
+    /// CHECK: Receives the rent on close — validated against the transfer's rent recipient
+    #[account(
+        mut,
+        constraint = transfer.rent_recipient() == rent_to.key() @ HandshakeError::InvalidRentRecipient
+    )]
+    pub rent_to: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
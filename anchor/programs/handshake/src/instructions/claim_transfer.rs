@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{transfer_checked, TransferChecked, Mint, TokenAccount, TokenInterface};
-use crate::{state::*, errors::*, constants::*};
+use anchor_spl::token_interface::{TransferChecked, Mint, TokenAccount, TokenInterface};
+use crate::{state::*, errors::*, constants::*, token_extensions};
 
 /// Claim an active transfer as the recipient
 pub fn claim_transfer<'a, 'b, 'c, 'info>(
@@ -13,7 +13,7 @@ pub fn claim_transfer<'a, 'b, 'c, 'info>(
     transfer.validate_recipient_can_claim(ctx.accounts.recipient.key())?;
 
     // Calculate fee
-    let fee = pool.calculate_transfer_fee(transfer.amount);
+    let fee = pool.transfer_fee(ctx.accounts.pool_mint.as_deref(), transfer.amount);
     let net_amount = transfer.amount
         .checked_sub(fee)
         .ok_or(HandshakeError::CalculationError)?;
@@ -33,16 +33,17 @@ pub fn claim_transfer<'a, 'b, 'c, 'info>(
         transfer_accounts,
         pool_signer_seeds,
     );
-    transfer_checked(cpi_ctx, net_amount, ctx.accounts.mint.decimals)?;
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        net_amount,
+        ctx.accounts.mint.decimals,
+    )?;
 
     // Update pool accounting
-    pool.add_withdrawal(transfer.amount)?;
-    if fee > 0 {
-        pool.add_collected_fees(fee)?;
-    }
-    pool.increment_transfers_resolved()?;
+    pool.book_withdrawal(ctx.accounts.pool_mint.as_deref_mut(), transfer.amount, fee)?;
 
-    // Mark transfer as claimed and close (rent to sender)
+    // Mark transfer as claimed (closed to rent_to)
     transfer.mark_as_claimed()?;
 
     emit!(TransferClaimed {
@@ -76,14 +77,14 @@ pub struct ClaimTransfer<'info> {
 
     /// The mint for validation
     #[account(
-        constraint = mint.key() == pool.mint
+        constraint = mint.key() == pool.transfer_mint(pool_mint.as_deref())
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// Pool's token account
     #[account(
         mut,
-        associated_token::mint = pool.mint,
+        associated_token::mint = mint,
         associated_token::authority = pool,
         associated_token::token_program = token_program
     )]
@@ -92,28 +93,40 @@ pub struct ClaimTransfer<'info> {
     /// Recipient's token account to receive funds
     #[account(
         mut,
-        associated_token::mint = pool.mint,
+        associated_token::mint = mint,
         associated_token::authority = recipient,
         associated_token::token_program = token_program
     )]
     pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Transfer account to claim (closed to sender on success)
+    /// Transfer account to claim (closed to rent_to on success)
     #[account(
         mut,
-        close = sender,
-        constraint = transfer.pool == pool.key()
+        close = rent_to,
+        constraint = transfer.pool == pool_mint.as_ref().map_or(pool.key(), |pool_mint| pool_mint.key())
     )]
     pub transfer: Box<Account<'info, SecureTransfer>>,
 
-    /// CHECK: Sender receives rent refund on close.
+    /// CHECK: Receives the rent on close — validated against the transfer's rent recipient
     #[account(
         mut,
-        constraint = transfer.sender == sender.key() @ HandshakeError::Unauthorized
+        constraint = transfer.rent_recipient() == rent_to.key() @ HandshakeError::InvalidRentRecipient
     )]
-    pub sender: AccountInfo<'info>,
+    pub rent_to: AccountInfo<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Child of the transfer's mint, when it is one added with `add_pool_mint`
+    #[account(
+        mut,
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            pool_mint.mint.as_ref()
+        ],
+        bump = pool_mint.bump
+    )]
+    pub pool_mint: Option<Account<'info, PoolMint>>,
 }
 
 #[event]
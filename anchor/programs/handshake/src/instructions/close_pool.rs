@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
-    close_account, transfer_checked, CloseAccount, TransferChecked, Mint, TokenAccount, TokenInterface,
+    close_account, CloseAccount, TransferChecked, Mint, TokenAccount, TokenInterface,
 };
-use crate::{state::*, errors::*, constants::*};
+use crate::{state::*, errors::*, constants::*, token_extensions};
 
 /// Close the pool (operator only, requires no outstanding transfers)
-pub fn close_pool(ctx: Context<ClosePool>, withdrawal_amount: u64) -> Result<()> {
+///
+/// Nothing may be escrowed. Unwithdrawn fees are paid to the operator and only
+/// the remainder of the vault above them is swept, so closing can never take
+/// funds the pool still owes. Token-2022 transfer fees withheld in the vault are
+/// harvested to the mint, since the vault cannot close while it holds them.
+pub fn close_pool<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClosePool<'info>>,
+) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
 
     // Validate operator
@@ -19,18 +26,25 @@ pub fn close_pool(ctx: Context<ClosePool>, withdrawal_amount: u64) -> Result<()>
         !pool.has_outstanding_transfers(),
         HandshakeError::OutstandingTransfers
     );
-
-    // Validate withdrawal amount matches pool balance
     require!(
-        withdrawal_amount <= ctx.accounts.pool_token_account.amount,
-        HandshakeError::InsufficientFunds
+        pool.total_escrowed == 0,
+        HandshakeError::EscrowedFundsRemaining
     );
 
+    // Added mints are removed (and their vaults emptied) first
+    require!(pool.mint_count == 0, HandshakeError::PoolHasMints);
+
+    // Fees first; whatever is left above them is surplus
+    let vault_balance = ctx.accounts.pool_token_account.amount;
+    let collected_fees = pool.collected_fees;
+    let fees_paid = collected_fees.min(vault_balance);
+    let surplus_swept = vault_balance - fees_paid;
+
     let pool_seeds = &[POOL_SEED, pool.pool_id.as_ref(), &[pool.bump]];
     let pool_signer_seeds = &[&pool_seeds[..]];
 
-    // Transfer remaining tokens to operator
-    if withdrawal_amount > 0 {
+    // Transfer fees and surplus to operator
+    if vault_balance > 0 {
         let transfer_accounts = TransferChecked {
             from: ctx.accounts.pool_token_account.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
@@ -42,9 +56,21 @@ pub fn close_pool(ctx: Context<ClosePool>, withdrawal_amount: u64) -> Result<()>
             transfer_accounts,
             pool_signer_seeds,
         );
-        transfer_checked(cpi_ctx, withdrawal_amount, ctx.accounts.mint.decimals)?;
+        token_extensions::transfer_checked(
+            cpi_ctx,
+            ctx.remaining_accounts,
+            vault_balance,
+            ctx.accounts.mint.decimals,
+        )?;
     }
 
+    // Withheld transfer fees belong to the mint's fee authority, not the operator
+    let withheld_fees_harvested = token_extensions::harvest_withheld_fees(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.pool_token_account.to_account_info(),
+    )?;
+
     // Close the pool token account (reclaim rent to operator)
     let close_accounts = CloseAccount {
         account: ctx.accounts.pool_token_account.to_account_info(),
@@ -61,7 +87,11 @@ pub fn close_pool(ctx: Context<ClosePool>, withdrawal_amount: u64) -> Result<()>
     emit!(PoolClosed {
         pool: pool.key(),
         operator: pool.operator,
-        withdrawal_amount,
+        mint: pool.mint,
+        collected_fees,
+        fees_paid,
+        surplus_swept,
+        withheld_fees_harvested,
     });
 
     Ok(())
@@ -84,8 +114,9 @@ pub struct ClosePool<'info> {
     )]
     pub pool: Box<Account<'info, Pool>>,
 
-    /// The mint for validation
+    /// The mint for validation (receives harvested withheld fees)
     #[account(
+        mut,
         constraint = mint.key() == pool.mint
     )]
     pub mint: InterfaceAccount<'info, Mint>,
@@ -115,5 +146,11 @@ pub struct ClosePool<'info> {
 pub struct PoolClosed {
     pub pool: Pubkey,
     pub operator: Pubkey,
-    pub withdrawal_amount: u64,
+    pub mint: Pubkey,
+    /// Fees on the books at close
+    pub collected_fees: u64,
+    pub fees_paid: u64,
+    pub surplus_swept: u64,
+    /// Token-2022 transfer fees moved from the vault to the mint
+    pub withheld_fees_harvested: u64,
 }
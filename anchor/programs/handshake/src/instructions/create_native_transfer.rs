@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{sync_native, SyncNative, TokenAccount, TokenInterface},
+};
+use crate::{state::*, errors::*, constants::*};
+use super::create_transfer::TransferCreated;
+
+/// Create a transfer (escrow) in a native SOL pool, paid in lamports
+///
+/// The lamports are wrapped straight into the pool's wSOL account, so the sender
+/// needs no token account. The transfer then follows the usual lifecycle; refunds
+/// are paid in wSOL to the sender's wSOL ATA unless `refund_to` names another one.
+pub fn create_native_transfer(
+    ctx: Context<CreateNativeTransfer>,
+    recipient: Pubkey,
+    nonce: u64,
+    amount: u64,
+    memo: String,
+    claimable_after: i64,
+    claimable_until: i64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let transfer_account = &mut ctx.accounts.transfer;
+
+    // Validate pool is not paused
+    require!(!pool.is_paused, HandshakeError::PoolPaused);
+
+    // Validate amount
+    require!(amount > 0, HandshakeError::DepositTooSmall);
+
+    // Wrap the lamports into the pool's wSOL account
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative {
+            account: ctx.accounts.pool_token_account.to_account_info(),
+        },
+    ))?;
+
+    let refund_to = ctx.accounts.refund_to.as_ref().map_or_else(
+        || {
+            get_associated_token_address_with_program_id(
+                &ctx.accounts.sender.key(),
+                &pool.mint,
+                &ctx.accounts.token_program.key(),
+            )
+        },
+        |account| account.key(),
+    );
+    let rent_to = ctx
+        .accounts
+        .rent_to
+        .as_ref()
+        .map_or(ctx.accounts.sender.key(), |account| account.key());
+
+    // Initialize transfer account
+    transfer_account.initialize(
+        ctx.bumps.transfer,
+        nonce,
+        ctx.accounts.sender.key(),
+        recipient,
+        pool.key(),
+        refund_to,
+        rent_to,
+        amount,
+        memo.clone(),
+        claimable_after,
+        claimable_until,
+    )?;
+
+    // Update pool accounting
+    pool.add_deposit(amount)?;
+    pool.increment_transfers_created()?;
+
+    emit!(TransferCreated {
+        transfer: transfer_account.key(),
+        pool: pool.key(),
+        sender: transfer_account.sender,
+        recipient: transfer_account.recipient,
+        amount,
+        transfer_fee: 0,
+        nonce,
+        memo,
+        claimable_after,
+        claimable_until,
+        refund_to,
+        rent_to,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey, nonce: u64)]
+pub struct CreateNativeTransfer<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// The pool this transfer belongs to — must escrow native SOL
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.pool_id.as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_native() @ HandshakeError::NotNativePool
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Pool's wSOL account where funds are stored
+    #[account(
+        mut,
+        associated_token::mint = pool.mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Transfer account - PDA derived from sender, recipient, and nonce
+    #[account(
+        init,
+        payer = sender,
+        space = SecureTransfer::SPACE,
+        seeds = [
+            SENDER_SEED,
+            sender.key().as_ref(),
+            RECIPIENT_SEED,
+            recipient.key().as_ref(),
+            NONCE_SEED,
+            nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub transfer: Box<Account<'info, SecureTransfer>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// wSOL refund destination if not the sender's wSOL ATA
+    #[account(
+        token::mint = pool.mint,
+        token::token_program = token_program
+    )]
+    pub refund_to: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Rent recipient on close if not `sender` — any account can receive lamports
+    pub rent_to: Option<UncheckedAccount<'info>>,
+}
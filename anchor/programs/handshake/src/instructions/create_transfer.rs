@@ -3,9 +3,17 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{transfer_checked, TransferChecked, Mint, TokenAccount, TokenInterface},
 };
-use crate::{state::*, errors::*, constants::*};
+use crate::{state::*, errors::*, constants::*, token_extensions};
 
 /// Create a new transfer (escrow)
+///
+/// The sender may be a PDA signing through CPI, funding from any token account it
+/// owns. Refunds go back to that account and the rent back to the sender unless
+/// `refund_to` / `rent_to` redirect them. The sender pays the transfer's rent, so a
+/// sender PDA that holds data must pre-fund the transfer address first.
+///
+/// For a mint added with `add_pool_mint`, pass its `pool_mint` child: the transfer
+/// is booked there, at that mint's fee, and belongs to the child.
 pub fn create_transfer<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, CreateTransfer<'info>>,
     recipient: Pubkey,
@@ -24,7 +32,11 @@ pub fn create_transfer<'a, 'b, 'c, 'info>(
     // Validate amount
     require!(amount > 0, HandshakeError::DepositTooSmall);
 
+    // Extensions can change after the pool was created (e.g. a hook program set later)
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info())?;
+
     // Transfer tokens from sender to pool
+    let balance_before = ctx.accounts.pool_token_account.amount;
     let transfer_accounts = TransferChecked {
         from: ctx.accounts.sender_token_account.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
@@ -37,33 +49,65 @@ pub fn create_transfer<'a, 'b, 'c, 'info>(
     );
     transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
 
+    // Book what the pool received: a Token-2022 transfer fee is withheld from it
+    ctx.accounts.pool_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .pool_token_account
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(HandshakeError::MathOverflow)?;
+    require!(received > 0, HandshakeError::DepositTooSmall);
+
+    let refund_to = ctx
+        .accounts
+        .refund_to
+        .as_ref()
+        .map_or(ctx.accounts.sender_token_account.key(), |account| account.key());
+    let rent_to = ctx
+        .accounts
+        .rent_to
+        .as_ref()
+        .map_or(ctx.accounts.sender.key(), |account| account.key());
+
+    // Transfers in an added mint belong to its child
+    let transfer_pool = ctx
+        .accounts
+        .pool_mint
+        .as_ref()
+        .map_or(pool.key(), |pool_mint| pool_mint.key());
+
     // Initialize transfer account
     transfer.initialize(
         ctx.bumps.transfer,
         nonce,
         ctx.accounts.sender.key(),
         recipient,
-        pool.key(),
-        amount,
+        transfer_pool,
+        refund_to,
+        rent_to,
+        received,
         memo.clone(),
         claimable_after,
         claimable_until,
     )?;
 
     // Update pool accounting
-    pool.add_deposit(amount)?;
-    pool.increment_transfers_created()?;
+    pool.book_deposit(ctx.accounts.pool_mint.as_deref_mut(), received)?;
 
     emit!(TransferCreated {
         transfer: transfer.key(),
         pool: pool.key(),
         sender: transfer.sender,
         recipient: transfer.recipient,
-        amount,
+        amount: received,
+        transfer_fee: amount - received,
         nonce,
         memo,
         claimable_after,
         claimable_until,
+        refund_to,
+        rent_to,
     });
 
     Ok(())
@@ -88,25 +132,25 @@ pub struct CreateTransfer<'info> {
 
     /// The mint for validation
     #[account(
-        constraint = mint.key() == pool.mint
+        constraint = mint.key() == pool.transfer_mint(pool_mint.as_deref())
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// Pool's token account where funds are stored
     #[account(
         mut,
-        associated_token::mint = pool.mint,
+        associated_token::mint = mint,
         associated_token::authority = pool,
         associated_token::token_program = token_program
     )]
     pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Sender's token account
+    /// Sender's token account (any account the sender owns) — recorded as the refund destination
     #[account(
         mut,
-        associated_token::mint = pool.mint,
-        associated_token::authority = sender,
-        associated_token::token_program = token_program
+        token::mint = mint,
+        token::authority = sender,
+        token::token_program = token_program
     )]
     pub sender_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -130,6 +174,28 @@ pub struct CreateTransfer<'info> {
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Refund destination if not `sender_token_account` (e.g. cold storage)
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub refund_to: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Rent recipient on close if not `sender` — any account can receive lamports
+    pub rent_to: Option<UncheckedAccount<'info>>,
+
+    /// Child of the transfer's mint, when it is one added with `add_pool_mint`
+    #[account(
+        mut,
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            pool_mint.mint.as_ref()
+        ],
+        bump = pool_mint.bump
+    )]
+    pub pool_mint: Option<Account<'info, PoolMint>>,
 }
 
 #[event]
@@ -139,8 +205,11 @@ pub struct TransferCreated {
     pub sender: Pubkey,
     pub recipient: Pubkey,
     pub amount: u64,
+    pub transfer_fee: u64,
     pub nonce: u64,
     pub memo: String,
     pub claimable_after: i64,
     pub claimable_until: i64,
+    pub refund_to: Pubkey,
+    pub rent_to: Pubkey,
 }
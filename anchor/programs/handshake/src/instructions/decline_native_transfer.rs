@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, native::*};
+use super::decline_transfer::TransferDeclined;
+
+/// Decline a native SOL pool transfer as the recipient and refund the sender in lamports
+///
+/// The recipient fronts the unwrap account's rent and gets it back in the same call.
+pub fn decline_native_transfer(
+    ctx: Context<DeclineNativeTransfer>,
+    reason: Option<u8>,
+) -> Result<()> {
+    let refund = &mut ctx.accounts.refund;
+
+    // Validate recipient
+    require!(
+        refund.payer.key() == refund.transfer.recipient,
+        HandshakeError::Unauthorized
+    );
+
+    // Validate transfer is active
+    refund.transfer.validate_active()?;
+
+    // Refund full amount as lamports (no fee on decline)
+    refund.refund()?;
+
+    // Mark transfer as declined
+    refund.transfer.mark_as_declined()?;
+
+    emit!(TransferDeclined {
+        transfer: refund.transfer.key(),
+        pool: refund.pool.key(),
+        sender: refund.transfer.sender,
+        recipient: refund.transfer.recipient,
+        amount: refund.transfer.amount,
+        reason,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeclineNativeTransfer<'info> {
+    /// Recipient as `payer`
+    pub refund: NativeRefund<'info>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, native::*};
+use super::destroy_transfer::TransferDestroyed;
+
+/// Emergency: destroy a native SOL pool transfer (operator only, pool must be paused)
+/// and refund the sender in lamports
+///
+/// The operator fronts the unwrap account's rent and gets it back in the same call.
+pub fn destroy_native_transfer(ctx: Context<DestroyNativeTransfer>) -> Result<()> {
+    let refund = &mut ctx.accounts.refund;
+
+    // Validate operator
+    require!(
+        refund.payer.key() == refund.pool.operator,
+        HandshakeError::Unauthorized
+    );
+
+    // Validate pool is paused
+    require!(refund.pool.is_paused, HandshakeError::PoolPaused);
+
+    // Validate transfer is active
+    refund.transfer.validate_active()?;
+
+    // Return escrowed amount to the sender as lamports (NOT the operator)
+    refund.refund()?;
+
+    // Mark as cancelled (closed to rent_to)
+    refund.transfer.mark_as_cancelled()?;
+
+    emit!(TransferDestroyed {
+        transfer: refund.transfer.key(),
+        pool: refund.pool.key(),
+        sender: refund.transfer.sender,
+        recipient: refund.transfer.recipient,
+        amount: refund.transfer.amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DestroyNativeTransfer<'info> {
+    /// Pool operator as `payer`
+    pub refund: NativeRefund<'info>,
+}
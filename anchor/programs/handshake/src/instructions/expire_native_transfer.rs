@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, native::*};
+use super::expire_transfer::TransferExpired;
+
+/// Expire a native SOL pool transfer past its claimable_until deadline and refund the
+/// sender in lamports (permissionless)
+///
+/// The caller fronts the unwrap account's rent and gets it back in the same call.
+pub fn expire_native_transfer(ctx: Context<ExpireNativeTransfer>) -> Result<()> {
+    let refund = &mut ctx.accounts.refund;
+
+    // Validate transfer is active before doing any work
+    refund.transfer.validate_active()?;
+
+    // Validate transfer is expired
+    require!(
+        refund.transfer.claimable_until > 0,
+        HandshakeError::InvalidTimeWindow
+    );
+
+    let is_expired = refund.transfer.is_expired()?;
+    require!(is_expired, HandshakeError::CannotClaim);
+
+    // Refund full amount as lamports (NO fee on expiry)
+    refund.refund()?;
+
+    // Mark transfer as expired
+    refund.transfer.mark_as_expired()?;
+
+    emit!(TransferExpired {
+        transfer: refund.transfer.key(),
+        pool: refund.pool.key(),
+        sender: refund.transfer.sender,
+        recipient: refund.transfer.recipient,
+        amount: refund.transfer.amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireNativeTransfer<'info> {
+    /// Anyone can call this (permissionless) as `payer`
+    pub refund: NativeRefund<'info>,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{transfer_checked, TransferChecked, Mint, TokenAccount, TokenInterface};
-use crate::{state::*, errors::*, constants::*};
+use anchor_spl::token_interface::{TransferChecked, Mint, TokenAccount, TokenInterface};
+use crate::{state::*, errors::*, constants::*, token_extensions};
 
 /// Expire a transfer past its claimable_until deadline (permissionless)
 pub fn expire_transfer<'a, 'b, 'c, 'info>(
@@ -21,14 +21,14 @@ pub fn expire_transfer<'a, 'b, 'c, 'info>(
     let is_expired = transfer.is_expired()?;
     require!(is_expired, HandshakeError::CannotClaim);
 
-    // Transfer full amount back to sender (NO fee on expiry)
+    // Refund full amount to the recorded refund account (NO fee on expiry)
     let pool_seeds = &[POOL_SEED, pool.pool_id.as_ref(), &[pool.bump]];
     let pool_signer_seeds = &[&pool_seeds[..]];
 
     let transfer_accounts = TransferChecked {
         from: ctx.accounts.pool_token_account.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.sender_token_account.to_account_info(),
+        to: ctx.accounts.refund_to.to_account_info(),
         authority: pool.to_account_info(),
     };
     let cpi_ctx = CpiContext::new_with_signer(
@@ -36,11 +36,15 @@ pub fn expire_transfer<'a, 'b, 'c, 'info>(
         transfer_accounts,
         pool_signer_seeds,
     );
-    transfer_checked(cpi_ctx, transfer.amount, ctx.accounts.mint.decimals)?;
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        transfer.amount,
+        ctx.accounts.mint.decimals,
+    )?;
 
     // Update pool accounting
-    pool.add_withdrawal(transfer.amount)?;
-    pool.increment_transfers_resolved()?;
+    pool.book_withdrawal(ctx.accounts.pool_mint.as_deref_mut(), transfer.amount, 0)?;
 
     // Mark transfer as expired
     transfer.mark_as_expired()?;
@@ -74,44 +78,59 @@ pub struct ExpireTransfer<'info> {
 
     /// The mint for validation
     #[account(
-        constraint = mint.key() == pool.mint
+        constraint = mint.key() == pool.transfer_mint(pool_mint.as_deref())
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// Pool's token account
     #[account(
         mut,
-        associated_token::mint = pool.mint,
+        associated_token::mint = mint,
         associated_token::authority = pool,
         associated_token::token_program = token_program
     )]
     pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Sender's token account to receive refund
+    /// Refund destination recorded on the transfer
     #[account(
         mut,
-        associated_token::mint = pool.mint,
-        associated_token::authority = transfer.sender,
-        associated_token::token_program = token_program
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = transfer.accepts_refund_to(
+            &refund_to.key(),
+            &refund_to.owner
+        ) @ HandshakeError::InvalidRefundAccount
     )]
-    pub sender_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub refund_to: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Transfer account to expire (closed to sender)
+    /// Transfer account to expire (closed to rent_to)
     #[account(
         mut,
-        close = sender,
-        constraint = transfer.pool == pool.key()
+        close = rent_to,
+        constraint = transfer.pool == pool_mint.as_ref().map_or(pool.key(), |pool_mint| pool_mint.key())
     )]
     pub transfer: Box<Account<'info, SecureTransfer>>,
 
-    /// CHECK: Sender receives rent refund on close.
+    /// CHECK: Receives the rent on close — validated against the transfer's rent recipient
     #[account(
         mut,
-        constraint = transfer.sender == sender.key() @ HandshakeError::Unauthorized
+        constraint = transfer.rent_recipient() == rent_to.key() @ HandshakeError::InvalidRentRecipient
     )]
-    pub sender: AccountInfo<'info>,
+    pub rent_to: AccountInfo<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Child of the transfer's mint, when it is one added with `add_pool_mint`
+    #[account(
+        mut,
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            pool_mint.mint.as_ref()
+        ],
+        bump = pool_mint.bump
+    )]
+    pub pool_mint: Option<Account<'info, PoolMint>>,
 }
 
 #[event]
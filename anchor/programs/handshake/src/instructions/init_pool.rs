@@ -3,7 +3,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
-use crate::{state::*, errors::*, constants::*};
+use crate::{state::*, errors::*, constants::*, token_extensions};
 
 /// Initialize a new escrow pool for a specific token
 pub fn init_pool(
@@ -20,6 +20,8 @@ pub fn init_pool(
         HandshakeError::InvalidTransferFee
     );
 
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info())?;
+
     // Initialize pool account
     pool.version = 1;
     pool.bump = ctx.bumps.pool;
@@ -36,6 +38,7 @@ pub fn init_pool(
     pool.total_transfers_resolved = 0;
     pool.collected_fees = 0;
     pool.is_paused = false;
+    pool.mint_count = 0;
 
     emit!(PoolCreated {
         pool: pool.key(),
@@ -1,25 +1,47 @@
 mod init_pool;
+mod add_pool_mint;
+mod remove_pool_mint;
 mod create_transfer;
+mod create_native_transfer;
 mod claim_transfer;
+mod claim_native_transfer;
 mod cancel_transfer;
+mod cancel_native_transfer;
+mod update_refund_to;
 mod reject_transfer;
+mod reject_native_transfer;
 mod decline_transfer;
+mod decline_native_transfer;
 mod expire_transfer;
+mod expire_native_transfer;
 mod withdraw_fees;
 mod destroy_transfer;
+mod destroy_native_transfer;
 mod pause_pool;
 mod reset_pool;
+mod reconcile_pool;
 mod close_pool;
 
 pub use init_pool::*;
+pub use add_pool_mint::*;
+pub use remove_pool_mint::*;
 pub use create_transfer::*;
+pub use create_native_transfer::*;
 pub use claim_transfer::*;
+pub use claim_native_transfer::*;
 pub use cancel_transfer::*;
+pub use cancel_native_transfer::*;
+pub use update_refund_to::*;
 pub use reject_transfer::*;
+pub use reject_native_transfer::*;
 pub use decline_transfer::*;
+pub use decline_native_transfer::*;
 pub use expire_transfer::*;
+pub use expire_native_transfer::*;
 pub use withdraw_fees::*;
 pub use destroy_transfer::*;
+pub use destroy_native_transfer::*;
 pub use pause_pool::*;
 pub use reset_pool::*;
+pub use reconcile_pool::*;
 pub use close_pool::*;
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, Mint, TokenAccount, TokenInterface};
+use crate::{state::*, errors::*, constants::*, token_extensions};
+
+/// Compare a vault with what the pool owes from it (permissionless)
+///
+/// Anything above `total_escrowed + collected_fees` (donations, token extension
+/// effects) is surplus; anything below is a deficit. With `sweep_surplus` the
+/// operator moves the surplus — and only the surplus — to their token account.
+pub fn reconcile_pool<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ReconcilePool<'info>>,
+    sweep_surplus: bool,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    let (total_escrowed, collected_fees) = match ctx.accounts.pool_mint.as_deref() {
+        Some(pool_mint) => (pool_mint.total_escrowed, pool_mint.collected_fees),
+        None => (pool.total_escrowed, pool.collected_fees),
+    };
+    let owed = total_escrowed
+        .checked_add(collected_fees)
+        .ok_or(HandshakeError::MathOverflow)?;
+
+    let vault_balance = ctx.accounts.pool_token_account.amount;
+    let surplus = vault_balance.saturating_sub(owed);
+    let deficit = owed.saturating_sub(vault_balance);
+
+    let mut swept = 0;
+    if sweep_surplus {
+        // Validate operator
+        require!(
+            ctx.accounts.caller.key() == pool.operator,
+            HandshakeError::Unauthorized
+        );
+        let operator_token_account = ctx
+            .accounts
+            .operator_token_account
+            .as_ref()
+            .ok_or(HandshakeError::MissingSweepAccount)?;
+
+        if surplus > 0 {
+            let pool_seeds = &[POOL_SEED, pool.pool_id.as_ref(), &[pool.bump]];
+            let pool_signer_seeds = &[&pool_seeds[..]];
+
+            let transfer_accounts = TransferChecked {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: operator_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                pool_signer_seeds,
+            );
+            token_extensions::transfer_checked(
+                cpi_ctx,
+                ctx.remaining_accounts,
+                surplus,
+                ctx.accounts.mint.decimals,
+            )?;
+            swept = surplus;
+        }
+    }
+
+    emit!(PoolReconciled {
+        pool: pool.key(),
+        mint: ctx.accounts.mint.key(),
+        vault_balance,
+        total_escrowed,
+        collected_fees,
+        surplus,
+        deficit,
+        swept,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReconcilePool<'info> {
+    /// Anyone can reconcile; only the operator can sweep
+    pub caller: Signer<'info>,
+
+    /// The pool
+    #[account(
+        seeds = [
+            POOL_SEED,
+            pool.pool_id.as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The mint for validation
+    #[account(
+        constraint = mint.key() == pool.transfer_mint(pool_mint.as_deref())
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Pool's token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Operator's token account to receive the surplus (needed to sweep)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool.operator,
+        associated_token::token_program = token_program
+    )]
+    pub operator_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Child of the mint, when it is one added with `add_pool_mint`
+    #[account(
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            pool_mint.mint.as_ref()
+        ],
+        bump = pool_mint.bump
+    )]
+    pub pool_mint: Option<Account<'info, PoolMint>>,
+}
+
+#[event]
+pub struct PoolReconciled {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub vault_balance: u64,
+    pub total_escrowed: u64,
+    pub collected_fees: u64,
+    pub surplus: u64,
+    pub deficit: u64,
+    pub swept: u64,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, native::*};
+use super::reject_transfer::TransferRejected;
+
+/// Reject a native SOL pool transfer as the operator and refund the sender in lamports
+///
+/// The operator fronts the unwrap account's rent and gets it back in the same call.
+pub fn reject_native_transfer(
+    ctx: Context<RejectNativeTransfer>,
+    reason: Option<u8>,
+) -> Result<()> {
+    let refund = &mut ctx.accounts.refund;
+
+    // Validate operator
+    require!(
+        refund.payer.key() == refund.pool.operator,
+        HandshakeError::Unauthorized
+    );
+
+    // Validate transfer is active
+    refund.transfer.validate_active()?;
+
+    // Refund full amount as lamports (no fee on rejection)
+    refund.refund()?;
+
+    // Mark transfer as rejected
+    refund.transfer.mark_as_rejected()?;
+
+    emit!(TransferRejected {
+        transfer: refund.transfer.key(),
+        pool: refund.pool.key(),
+        sender: refund.transfer.sender,
+        recipient: refund.transfer.recipient,
+        amount: refund.transfer.amount,
+        reason,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RejectNativeTransfer<'info> {
+    /// Pool operator as `payer`
+    pub refund: NativeRefund<'info>,
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, TransferChecked, Mint, TokenAccount, TokenInterface,
+};
+use crate::{state::*, errors::*, constants::*, token_extensions};
+
+/// Remove an added mint from the pool (operator only, nothing escrowed in it)
+///
+/// With nothing escrowed, the whole vault is the operator's: collected fees plus
+/// any stray balance go to the operator before the vault and child are closed.
+/// Token-2022 transfer fees withheld in the vault are harvested to the mint first,
+/// since the vault cannot close while it holds them.
+pub fn remove_pool_mint<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, RemovePoolMint<'info>>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let pool_mint = &ctx.accounts.pool_mint;
+
+    // Validate operator
+    require!(
+        ctx.accounts.operator.key() == pool.operator,
+        HandshakeError::Unauthorized
+    );
+
+    // Validate no outstanding transfers in this mint
+    require!(
+        pool_mint.total_escrowed == 0,
+        HandshakeError::OutstandingTransfers
+    );
+
+    // Fees first; whatever is left above them is surplus
+    let vault_balance = ctx.accounts.pool_token_account.amount;
+    let collected_fees = pool_mint.collected_fees;
+    let fees_paid = collected_fees.min(vault_balance);
+    let surplus_swept = vault_balance - fees_paid;
+
+    let pool_seeds = &[POOL_SEED, pool.pool_id.as_ref(), &[pool.bump]];
+    let pool_signer_seeds = &[&pool_seeds[..]];
+
+    // Transfer fees and surplus to operator
+    if vault_balance > 0 {
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.pool_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.operator_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_accounts,
+            pool_signer_seeds,
+        );
+        token_extensions::transfer_checked(
+            cpi_ctx,
+            ctx.remaining_accounts,
+            vault_balance,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    // Withheld transfer fees belong to the mint's fee authority, not the operator
+    let withheld_fees_harvested = token_extensions::harvest_withheld_fees(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.pool_token_account.to_account_info(),
+    )?;
+
+    // Close the vault (reclaim rent to operator)
+    let close_accounts = CloseAccount {
+        account: ctx.accounts.pool_token_account.to_account_info(),
+        destination: ctx.accounts.operator.to_account_info(),
+        authority: pool.to_account_info(),
+    };
+    let close_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_accounts,
+        pool_signer_seeds,
+    );
+    close_account(close_cpi_ctx)?;
+
+    pool.mint_count = pool
+        .mint_count
+        .checked_sub(1)
+        .ok_or(HandshakeError::MathOverflow)?;
+
+    emit!(PoolMintRemoved {
+        pool: pool.key(),
+        pool_mint: pool_mint.key(),
+        mint: pool_mint.mint,
+        collected_fees,
+        fees_paid,
+        surplus_swept,
+        withheld_fees_harvested,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemovePoolMint<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.pool_id.as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The mint's child (will be closed)
+    #[account(
+        mut,
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            pool_mint.mint.as_ref()
+        ],
+        bump = pool_mint.bump,
+        close = operator
+    )]
+    pub pool_mint: Box<Account<'info, PoolMint>>,
+
+    /// The mint for validation (receives harvested withheld fees)
+    #[account(
+        mut,
+        constraint = mint.key() == pool_mint.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Pool's token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Operator's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = operator,
+        associated_token::token_program = token_program
+    )]
+    pub operator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct PoolMintRemoved {
+    pub pool: Pubkey,
+    pub pool_mint: Pubkey,
+    pub mint: Pubkey,
+    /// Fees on the child's books at removal
+    pub collected_fees: u64,
+    pub fees_paid: u64,
+    pub surplus_swept: u64,
+    /// Token-2022 transfer fees moved from the vault to the mint
+    pub withheld_fees_harvested: u64,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::{state::*, constants::*};
+
+/// Point an active transfer's refunds (and optionally its rent) somewhere new (sender only)
+///
+/// Every refund path pays the recorded `refund_to`, so a sender whose refund account
+/// was closed or frozen re-points it here instead of leaving the escrow stuck.
+pub fn update_refund_to(ctx: Context<UpdateRefundTo>) -> Result<()> {
+    let transfer = &mut ctx.accounts.transfer;
+
+    // Same authority as cancelling: the sender, while the transfer is active
+    transfer.validate_sender_can_cancel(ctx.accounts.sender.key())?;
+
+    transfer.refund_to = ctx.accounts.refund_to.key();
+    if let Some(rent_to) = &ctx.accounts.rent_to {
+        transfer.rent_to = rent_to.key();
+    }
+
+    emit!(TransferRefundToUpdated {
+        transfer: transfer.key(),
+        sender: transfer.sender,
+        refund_to: transfer.refund_to,
+        rent_to: transfer.rent_recipient(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateRefundTo<'info> {
+    pub sender: Signer<'info>,
+
+    /// The pool this transfer belongs to
+    #[account(
+        seeds = [
+            POOL_SEED,
+            pool.pool_id.as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The transfer's mint
+    #[account(
+        constraint = mint.key() == pool.transfer_mint(pool_mint.as_deref())
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Transfer to update
+    #[account(
+        mut,
+        constraint = transfer.pool == pool_mint.as_ref().map_or(pool.key(), |pool_mint| pool_mint.key())
+    )]
+    pub transfer: Box<Account<'info, SecureTransfer>>,
+
+    /// New refund destination — any token account for the transfer's mint
+    #[account(
+        token::mint = mint
+    )]
+    pub refund_to: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: New rent recipient on close; left unchanged if omitted — any account can receive lamports
+    pub rent_to: Option<UncheckedAccount<'info>>,
+
+    /// Child of the transfer's mint, when it is one added with `add_pool_mint`
+    #[account(
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            pool_mint.mint.as_ref()
+        ],
+        bump = pool_mint.bump
+    )]
+    pub pool_mint: Option<Account<'info, PoolMint>>,
+}
+
+#[event]
+pub struct TransferRefundToUpdated {
+    pub transfer: Pubkey,
+    pub sender: Pubkey,
+    pub refund_to: Pubkey,
+    pub rent_to: Pubkey,
+}
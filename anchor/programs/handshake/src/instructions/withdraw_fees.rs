@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{transfer_checked, TransferChecked, Mint, TokenAccount, TokenInterface};
-use crate::{state::*, errors::*, constants::*};
+use anchor_spl::token_interface::{TransferChecked, Mint, TokenAccount, TokenInterface};
+use crate::{state::*, errors::*, constants::*, token_extensions};
 
-/// Withdraw collected fees (operator only)
-pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+/// Withdraw collected fees of one of the pool's mints (operator only)
+pub fn withdraw_fees<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, WithdrawFees<'info>>,
+) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
 
     // Validate operator
@@ -12,7 +14,11 @@ pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
         HandshakeError::Unauthorized
     );
 
-    let fees = pool.collected_fees;
+    let fees = ctx
+        .accounts
+        .pool_mint
+        .as_ref()
+        .map_or(pool.collected_fees, |pool_mint| pool_mint.collected_fees);
     require!(fees > 0, HandshakeError::CalculationError);
 
     // Transfer fees to operator
@@ -30,14 +36,23 @@ pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
         transfer_accounts,
         pool_signer_seeds,
     );
-    transfer_checked(cpi_ctx, fees, ctx.accounts.mint.decimals)?;
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        fees,
+        ctx.accounts.mint.decimals,
+    )?;
 
     // Reset collected fees
-    pool.reset_collected_fees();
+    match ctx.accounts.pool_mint.as_deref_mut() {
+        Some(pool_mint) => pool_mint.reset_collected_fees(),
+        None => pool.reset_collected_fees(),
+    }
 
     emit!(FeesWithdrawn {
         pool: pool.key(),
         operator: pool.operator,
+        mint: ctx.accounts.mint.key(),
         amount: fees,
     });
 
@@ -62,14 +77,14 @@ pub struct WithdrawFees<'info> {
 
     /// The mint for validation
     #[account(
-        constraint = mint.key() == pool.mint
+        constraint = mint.key() == pool.transfer_mint(pool_mint.as_deref())
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// Pool's token account
     #[account(
         mut,
-        associated_token::mint = pool.mint,
+        associated_token::mint = mint,
         associated_token::authority = pool,
         associated_token::token_program = token_program
     )]
@@ -78,18 +93,31 @@ pub struct WithdrawFees<'info> {
     /// Operator's token account to receive fees
     #[account(
         mut,
-        associated_token::mint = pool.mint,
+        associated_token::mint = mint,
         associated_token::authority = operator,
         associated_token::token_program = token_program
     )]
     pub operator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Child of the transfer's mint, when it is one added with `add_pool_mint`
+    #[account(
+        mut,
+        seeds = [
+            POOL_MINT_SEED,
+            pool.key().as_ref(),
+            pool_mint.mint.as_ref()
+        ],
+        bump = pool_mint.bump
+    )]
+    pub pool_mint: Option<Account<'info, PoolMint>>,
 }
 
 #[event]
 pub struct FeesWithdrawn {
     pub pool: Pubkey,
     pub operator: Pubkey,
+    pub mint: Pubkey,
     pub amount: u64,
 }
@@ -3,7 +3,9 @@ use anchor_lang::prelude::*;
 mod constants;
 mod errors;
 mod instructions;
-mod state;
+mod native;
+pub mod state;
+pub mod token_extensions;
 
 use instructions::*;
 use state::*;
@@ -22,6 +24,16 @@ pub mod handshake {
         instructions::init_pool(ctx, pool_id, transfer_fee_bps)
     }
 
+    pub fn add_pool_mint(ctx: Context<AddPoolMint>, transfer_fee_bps: u16) -> Result<()> {
+        instructions::add_pool_mint(ctx, transfer_fee_bps)
+    }
+
+    pub fn remove_pool_mint<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, RemovePoolMint<'info>>,
+    ) -> Result<()> {
+        instructions::remove_pool_mint(ctx)
+    }
+
     pub fn create_transfer<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, CreateTransfer<'info>>,
         recipient: Pubkey,
@@ -42,18 +54,50 @@ pub mod handshake {
         )
     }
 
+    pub fn create_native_transfer(
+        ctx: Context<CreateNativeTransfer>,
+        recipient: Pubkey,
+        nonce: u64,
+        amount: u64,
+        memo: String,
+        claimable_after: i64,
+        claimable_until: i64,
+    ) -> Result<()> {
+        instructions::create_native_transfer(
+            ctx,
+            recipient,
+            nonce,
+            amount,
+            memo,
+            claimable_after,
+            claimable_until,
+        )
+    }
+
     pub fn claim_transfer<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, ClaimTransfer<'info>>,
     ) -> Result<()> {
         instructions::claim_transfer(ctx)
     }
 
+    pub fn claim_native_transfer(ctx: Context<ClaimNativeTransfer>) -> Result<()> {
+        instructions::claim_native_transfer(ctx)
+    }
+
     pub fn cancel_transfer<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, CancelTransfer<'info>>,
     ) -> Result<()> {
         instructions::cancel_transfer(ctx)
     }
 
+    pub fn cancel_native_transfer(ctx: Context<CancelNativeTransfer>) -> Result<()> {
+        instructions::cancel_native_transfer(ctx)
+    }
+
+    pub fn update_refund_to(ctx: Context<UpdateRefundTo>) -> Result<()> {
+        instructions::update_refund_to(ctx)
+    }
+
     pub fn reject_transfer<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, RejectTransfer<'info>>,
         reason: Option<u8>,
@@ -61,6 +105,13 @@ pub mod handshake {
         instructions::reject_transfer(ctx, reason)
     }
 
+    pub fn reject_native_transfer(
+        ctx: Context<RejectNativeTransfer>,
+        reason: Option<u8>,
+    ) -> Result<()> {
+        instructions::reject_native_transfer(ctx, reason)
+    }
+
     pub fn decline_transfer<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, DeclineTransfer<'info>>,
         reason: Option<u8>,
@@ -68,20 +119,39 @@ pub mod handshake {
         instructions::decline_transfer(ctx, reason)
     }
 
+    pub fn decline_native_transfer(
+        ctx: Context<DeclineNativeTransfer>,
+        reason: Option<u8>,
+    ) -> Result<()> {
+        instructions::decline_native_transfer(ctx, reason)
+    }
+
     pub fn expire_transfer<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, ExpireTransfer<'info>>,
     ) -> Result<()> {
         instructions::expire_transfer(ctx)
     }
 
-    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+    pub fn expire_native_transfer(ctx: Context<ExpireNativeTransfer>) -> Result<()> {
+        instructions::expire_native_transfer(ctx)
+    }
+
+    pub fn withdraw_fees<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, WithdrawFees<'info>>,
+    ) -> Result<()> {
         instructions::withdraw_fees(ctx)
     }
 
-    pub fn destroy_transfer(ctx: Context<DestroyTransfer>) -> Result<()> {
+    pub fn destroy_transfer<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DestroyTransfer<'info>>,
+    ) -> Result<()> {
         instructions::destroy_transfer(ctx)
     }
 
+    pub fn destroy_native_transfer(ctx: Context<DestroyNativeTransfer>) -> Result<()> {
+        instructions::destroy_native_transfer(ctx)
+    }
+
     pub fn pause_pool(ctx: Context<PausePool>, is_paused: bool) -> Result<()> {
         instructions::pause_pool(ctx, is_paused)
     }
@@ -90,7 +160,16 @@ pub mod handshake {
         instructions::reset_pool(ctx)
     }
 
-    pub fn close_pool(ctx: Context<ClosePool>, withdrawal_amount: u64) -> Result<()> {
-        instructions::close_pool(ctx, withdrawal_amount)
+    pub fn reconcile_pool<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ReconcilePool<'info>>,
+        sweep_surplus: bool,
+    ) -> Result<()> {
+        instructions::reconcile_pool(ctx, sweep_surplus)
+    }
+
+    pub fn close_pool<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClosePool<'info>>,
+    ) -> Result<()> {
+        instructions::close_pool(ctx)
     }
 }
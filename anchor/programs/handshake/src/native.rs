@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+use crate::{state::*, errors::HandshakeError, constants::*};
+
+/// Accounts shared by the instructions that refund a native SOL pool transfer to
+/// the sender in lamports. Each instruction checks who may act as `payer`.
+#[derive(Accounts)]
+pub struct NativeRefund<'info> {
+    /// Fronts the unwrap account's rent and gets it back in the same call
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The transfer's sender wallet — receives the lamports
+    #[account(
+        mut,
+        address = transfer.sender,
+        owner = System::id() @ HandshakeError::InvalidRefundAccount,
+        constraint = transfer.refunds_to_sender(
+            &pool.mint,
+            &token_program.key()
+        ) @ HandshakeError::InvalidRefundAccount
+    )]
+    pub sender: UncheckedAccount<'info>,
+
+    /// The pool this transfer belongs to — must escrow native SOL
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.pool_id.as_ref()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_native() @ HandshakeError::NotNativePool
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The mint for validation
+    #[account(
+        constraint = mint.key() == pool.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Pool's wSOL account
+    #[account(
+        mut,
+        associated_token::mint = pool.mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Temporary wSOL account, closed before the instruction ends
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            UNWRAP_SEED,
+            transfer.key().as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program
+    )]
+    pub unwrap_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Transfer being refunded (closed to rent_to)
+    #[account(
+        mut,
+        close = rent_to,
+        constraint = transfer.pool == pool.key()
+    )]
+    pub transfer: Box<Account<'info, SecureTransfer>>,
+
+    /// CHECK: Receives the rent on close — validated against the transfer's rent recipient
+    #[account(
+        mut,
+        constraint = transfer.rent_recipient() == rent_to.key() @ HandshakeError::InvalidRentRecipient
+    )]
+    pub rent_to: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NativeRefund<'info> {
+    /// Refund the full transfer amount to the sender's wallet as lamports (no fee)
+    /// and book it out of the pool
+    ///
+    /// The amount moves into the unwrap account, which is closed into the program-owned
+    /// transfer account; that account then pays the amount to the sender and the unwrap
+    /// rent back to `payer`, and is closed to `rent_to` when the instruction ends.
+    pub fn refund(&mut self) -> Result<()> {
+        let amount = self.transfer.amount;
+        let unwrap_rent = self.unwrap_account.to_account_info().lamports();
+
+        let pool_id = self.pool.pool_id;
+        let pool_seeds = &[POOL_SEED, pool_id.as_ref(), &[self.pool.bump]];
+        let pool_signer_seeds = &[&pool_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.pool_token_account.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.unwrap_account.to_account_info(),
+                    authority: self.pool.to_account_info(),
+                },
+                pool_signer_seeds,
+            ),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        // Closing a wSOL account releases all of its lamports into the transfer account
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.unwrap_account.to_account_info(),
+                destination: self.transfer.to_account_info(),
+                authority: self.pool.to_account_info(),
+            },
+            pool_signer_seeds,
+        ))?;
+
+        let transfer_info = self.transfer.to_account_info();
+        let sender_info = self.sender.to_account_info();
+        let payer_info = self.payer.to_account_info();
+
+        let released = amount
+            .checked_add(unwrap_rent)
+            .ok_or(HandshakeError::CalculationError)?;
+        let transfer_lamports = transfer_info
+            .lamports()
+            .checked_sub(released)
+            .ok_or(HandshakeError::CalculationError)?;
+        **transfer_info.try_borrow_mut_lamports()? = transfer_lamports;
+        **sender_info.try_borrow_mut_lamports()? = sender_info
+            .lamports()
+            .checked_add(amount)
+            .ok_or(HandshakeError::CalculationError)?;
+        **payer_info.try_borrow_mut_lamports()? = payer_info
+            .lamports()
+            .checked_add(unwrap_rent)
+            .ok_or(HandshakeError::CalculationError)?;
+
+        // Update pool accounting
+        self.pool.book_withdrawal(None, amount, 0)
+    }
+}
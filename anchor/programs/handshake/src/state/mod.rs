@@ -1,5 +1,7 @@
 mod pool;
+mod pool_mint;
 mod secure_transfer;
 
 pub use pool::*;
+pub use pool_mint::*;
 pub use secure_transfer::*;
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::errors::HandshakeError;
+use anchor_spl::{token::spl_token, token_2022::spl_token_2022};
+use crate::{errors::HandshakeError, state::PoolMint};
 
 #[account]
 pub struct Pool {
@@ -40,8 +41,11 @@ pub struct Pool {
     /// Emergency controls
     pub is_paused: bool,
 
+    /// Mints added beyond `mint`, each with a `PoolMint` child
+    pub mint_count: u8,
+
     /// Padding for future upgrades
-    pub _padding: [u8; 128],
+    pub _padding: [u8; 127],
 }
 
 impl Pool {
@@ -59,7 +63,8 @@ impl Pool {
         8 + // total_transfers_resolved
         8 + // collected_fees
         1 + // is_paused
-        128; // _padding
+        1 + // mint_count
+        127; // _padding
 
     /// Calculate transfer fee amount
     pub fn calculate_transfer_fee(&self, amount: u64) -> u64 {
@@ -131,6 +136,57 @@ impl Pool {
         self.collected_fees = 0;
     }
 
+    /// Mint a transfer moves: the child's mint when it uses an added mint
+    pub fn transfer_mint(&self, pool_mint: Option<&PoolMint>) -> Pubkey {
+        pool_mint.map_or(self.mint, |pool_mint| pool_mint.mint)
+    }
+
+    /// Fee on a claim, at the rate of the mint the transfer moves
+    pub fn transfer_fee(&self, pool_mint: Option<&PoolMint>, amount: u64) -> u64 {
+        match pool_mint {
+            Some(pool_mint) => pool_mint.calculate_transfer_fee(amount),
+            None => self.calculate_transfer_fee(amount),
+        }
+    }
+
+    /// Book a new transfer: amounts on its mint's ledger, the count on the pool
+    pub fn book_deposit(&mut self, pool_mint: Option<&mut PoolMint>, amount: u64) -> Result<()> {
+        match pool_mint {
+            Some(pool_mint) => pool_mint.add_deposit(amount)?,
+            None => self.add_deposit(amount)?,
+        }
+        self.increment_transfers_created()
+    }
+
+    /// Book a resolved transfer: amounts and fee on its mint's ledger, the count on the pool
+    pub fn book_withdrawal(
+        &mut self,
+        pool_mint: Option<&mut PoolMint>,
+        amount: u64,
+        fee: u64,
+    ) -> Result<()> {
+        match pool_mint {
+            Some(pool_mint) => {
+                pool_mint.add_withdrawal(amount)?;
+                if fee > 0 {
+                    pool_mint.add_collected_fees(fee)?;
+                }
+            }
+            None => {
+                self.add_withdrawal(amount)?;
+                if fee > 0 {
+                    self.add_collected_fees(fee)?;
+                }
+            }
+        }
+        self.increment_transfers_resolved()
+    }
+
+    /// Check if pool escrows wrapped SOL (either token program's native mint)
+    pub fn is_native(&self) -> bool {
+        self.mint == spl_token::native_mint::ID || self.mint == spl_token_2022::native_mint::ID
+    }
+
     /// Check if pool has outstanding transfers
     pub fn has_outstanding_transfers(&self) -> bool {
         self.total_transfers_created > self.total_transfers_resolved
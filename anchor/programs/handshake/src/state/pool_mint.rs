@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::errors::HandshakeError;
+
+/// Per-mint vault settings and stats for a mint added to a pool beyond its own.
+/// The vault is the pool's token account for the mint; transfers in this mint
+/// record this account as their `pool`.
+#[account]
+pub struct PoolMint {
+    /// Version for upgrades
+    pub version: u8,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Parent pool
+    pub pool: Pubkey,
+
+    /// Token mint this child escrows
+    pub mint: Pubkey,
+
+    /// Transfer fee in basis points (0-10000) for this mint
+    pub transfer_fee_bps: u16,
+
+    /// Cumulative deposits
+    pub total_deposits: u64,
+
+    /// Cumulative withdrawals
+    pub total_withdrawals: u64,
+
+    /// Current amount held in escrow for this mint
+    pub total_escrowed: u64,
+
+    /// Accumulated fees (can be withdrawn by operator)
+    pub collected_fees: u64,
+
+    /// Padding for future upgrades
+    pub _padding: [u8; 64],
+}
+
+impl PoolMint {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // version
+        1 + // bump
+        32 + // pool
+        32 + // mint
+        2 + // transfer_fee_bps
+        8 + // total_deposits
+        8 + // total_withdrawals
+        8 + // total_escrowed
+        8 + // collected_fees
+        64; // _padding
+
+    /// Calculate transfer fee amount
+    pub fn calculate_transfer_fee(&self, amount: u64) -> u64 {
+        if self.transfer_fee_bps == 0 {
+            return 0;
+        }
+        (amount as u128)
+            .checked_mul(self.transfer_fee_bps as u128)
+            .unwrap_or(0)
+            .checked_div(10000)
+            .unwrap_or(0) as u64
+    }
+
+    /// Add deposit (when creating transfer)
+    pub fn add_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposits = self
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(HandshakeError::MathOverflow)?;
+        self.total_escrowed = self
+            .total_escrowed
+            .checked_add(amount)
+            .ok_or(HandshakeError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Add withdrawal (when resolving transfer)
+    pub fn add_withdrawal(&mut self, amount: u64) -> Result<()> {
+        self.total_withdrawals = self
+            .total_withdrawals
+            .checked_add(amount)
+            .ok_or(HandshakeError::MathOverflow)?;
+        self.total_escrowed = self
+            .total_escrowed
+            .checked_sub(amount)
+            .ok_or(HandshakeError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Add collected fees
+    pub fn add_collected_fees(&mut self, amount: u64) -> Result<()> {
+        self.collected_fees = self
+            .collected_fees
+            .checked_add(amount)
+            .ok_or(HandshakeError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Reset collected fees to zero (after withdrawal)
+    pub fn reset_collected_fees(&mut self) {
+        self.collected_fees = 0;
+    }
+}
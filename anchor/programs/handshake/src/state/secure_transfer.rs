@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use crate::errors::HandshakeError;
 
 #[account]
@@ -16,7 +17,7 @@ pub struct SecureTransfer {
     pub sender: Pubkey,
     pub recipient: Pubkey,
 
-    /// Pool this transfer belongs to
+    /// Pool this transfer belongs to (its `PoolMint` child for an added mint)
     pub pool: Pubkey,
 
     /// Escrowed amount (in tokens)
@@ -39,8 +40,13 @@ pub struct SecureTransfer {
     /// Travel rule compliance data hash
     pub compliance_hash: Option<[u8; 32]>,
 
-    /// Padding for future upgrades
-    pub _padding: [u8; 64],
+    /// Token account refunds are paid to (defaults to the sender's source account).
+    /// Zero for transfers created before it was recorded: refunds go to any sender account.
+    pub refund_to: Pubkey,
+
+    /// Receives the rent when the transfer closes (defaults to the sender; zero = sender)
+    pub rent_to: Pubkey,
+    // `refund_to` and `rent_to` took the 64 reserved bytes, so older transfers read them as zero
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
@@ -83,7 +89,8 @@ impl SecureTransfer {
         (1 + (1 + 64)) + // release_conditions Option
         64 + // memo
         (1 + 32) + // compliance_hash Option
-        64; // _padding
+        32 + // refund_to
+        32; // rent_to (the former 64-byte _padding)
 
     /// Initialize a new transfer
     pub fn initialize(
@@ -93,6 +100,8 @@ impl SecureTransfer {
         sender: Pubkey,
         recipient: Pubkey,
         pool: Pubkey,
+        refund_to: Pubkey,
+        rent_to: Pubkey,
         amount: u64,
         memo: String,
         claimable_after: i64,
@@ -139,6 +148,8 @@ impl SecureTransfer {
         self.status = TransferStatus::Active;
         self.release_conditions = None;
         self.compliance_hash = None;
+        self.refund_to = refund_to;
+        self.rent_to = rent_to;
 
         // Convert memo to fixed-size array
         let mut memo_bytes = [0u8; 64];
@@ -183,6 +194,32 @@ impl SecureTransfer {
         Ok(self.claimable_until > 0 && clock.unix_timestamp > self.claimable_until)
     }
 
+    /// Whether the token account at `key`, owned by `owner`, may receive this transfer's refund
+    pub fn accepts_refund_to(&self, key: &Pubkey, owner: &Pubkey) -> bool {
+        if self.refund_to == Pubkey::default() {
+            *owner == self.sender
+        } else {
+            *key == self.refund_to
+        }
+    }
+
+    /// Whether a native refund may skip the wSOL account and pay the sender's wallet:
+    /// only while the refund still goes to the sender's own ATA
+    pub fn refunds_to_sender(&self, mint: &Pubkey, token_program: &Pubkey) -> bool {
+        let sender_ata =
+            get_associated_token_address_with_program_id(&self.sender, mint, token_program);
+        self.accepts_refund_to(&sender_ata, &self.sender)
+    }
+
+    /// Account that receives the rent when the transfer closes
+    pub fn rent_recipient(&self) -> Pubkey {
+        if self.rent_to == Pubkey::default() {
+            self.sender
+        } else {
+            self.rent_to
+        }
+    }
+
     /// Validate sender can cancel
     pub fn validate_sender_can_cancel(&self, sender: Pubkey) -> Result<()> {
         require!(
@@ -239,3 +276,55 @@ impl SecureTransfer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `SPACE` before `refund_to`/`rent_to`, which ended in 64 bytes of padding
+    const BASELINE_SPACE: usize = 374;
+
+    /// A transfer as created before `refund_to`/`rent_to`: every field, then zeroed padding
+    fn baseline_transfer() -> Vec<u8> {
+        let mut data = SecureTransfer::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[1, 254]); // version, bump
+        data.extend_from_slice(&7u64.to_le_bytes()); // nonce
+        data.extend_from_slice(Pubkey::new_from_array([1; 32]).as_ref()); // sender
+        data.extend_from_slice(Pubkey::new_from_array([2; 32]).as_ref()); // recipient
+        data.extend_from_slice(Pubkey::new_from_array([3; 32]).as_ref()); // pool
+        data.extend_from_slice(&1_000u64.to_le_bytes()); // amount
+        data.extend_from_slice(&100i64.to_le_bytes()); // created_at
+        data.extend_from_slice(&0i64.to_le_bytes()); // claimable_after
+        data.extend_from_slice(&0i64.to_le_bytes()); // claimable_until
+        data.push(0); // status: Active
+        data.extend_from_slice(&[1, 0]); // release_conditions: Some(TimeDelay, ..)
+        data.extend_from_slice(&[9; 64]);
+        data.extend_from_slice(&[b'm'; 64]); // memo
+        data.push(1); // compliance_hash: Some(..)
+        data.extend_from_slice(&[4; 32]);
+        data.resize(BASELINE_SPACE, 0); // _padding
+        data
+    }
+
+    #[test]
+    fn space_matches_baseline_layout() {
+        assert_eq!(SecureTransfer::SPACE, BASELINE_SPACE);
+    }
+
+    #[test]
+    fn baseline_transfer_reads_new_fields_as_zero() {
+        let data = baseline_transfer();
+        assert_eq!(data.len(), BASELINE_SPACE);
+
+        let transfer = SecureTransfer::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(transfer.amount, 1_000);
+        assert_eq!(transfer.status, TransferStatus::Active);
+        assert_eq!(transfer.compliance_hash, Some([4; 32]));
+        assert_eq!(transfer.refund_to, Pubkey::default());
+        assert_eq!(transfer.rent_to, Pubkey::default());
+
+        // Zero means "the sender", as before the fields existed
+        assert_eq!(transfer.rent_recipient(), transfer.sender);
+        assert!(transfer.accepts_refund_to(&Pubkey::new_unique(), &transfer.sender));
+    }
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::TransferFeeAmount, transfer_hook::TransferHook, BaseStateWithExtensions,
+            ExtensionType, StateWithExtensions,
+        },
+        state::{Account, Mint},
+    },
+    token_2022_extensions::{harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint},
+    token_interface::TransferChecked,
+};
+use crate::errors::HandshakeError;
+
+/// Token-2022 mint extensions a pool or a Silk account can hold. Accounting is in
+/// raw amounts, so interest-bearing and scaled UI amounts need no handling; transfer
+/// fees are absorbed by booking what actually arrives. A permanent delegate could
+/// drain the vault and a pausable mint could freeze refunds, so both are rejected.
+pub const SUPPORTED_MINT_EXTENSIONS: &[ExtensionType] = &[
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::ConfidentialTransferMint,
+    ExtensionType::ConfidentialTransferFeeConfig,
+    ExtensionType::InterestBearingConfig,
+    ExtensionType::TransferHook,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::GroupPointer,
+    ExtensionType::TokenGroup,
+    ExtensionType::GroupMemberPointer,
+    ExtensionType::TokenGroupMember,
+    ExtensionType::ScaledUiAmount,
+];
+
+/// Whether every extension on the mint is in `SUPPORTED_MINT_EXTENSIONS`. SPL Token
+/// mints have none and always pass.
+pub fn has_supported_extensions(mint: &AccountInfo) -> Result<bool> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(true);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint
+        .get_extension_types()?
+        .iter()
+        .all(|extension| SUPPORTED_MINT_EXTENSIONS.contains(extension)))
+}
+
+/// The program a Token-2022 transfer hook currently invokes, if one is set.
+pub fn transfer_hook_program(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint
+        .get_extension::<TransferHook>()
+        .ok()
+        .and_then(|transfer_hook| Option::<Pubkey>::from(transfer_hook.program_id)))
+}
+
+/// Reject mints whose extensions the pool cannot escrow safely. A transfer hook is
+/// only accepted while no hook program is set, so new escrows never depend on one.
+/// A hook set later only affects funds already escrowed, which leave through
+/// `transfer_checked` with its accounts.
+pub fn validate_mint(mint: &AccountInfo) -> Result<()> {
+    require!(
+        has_supported_extensions(mint)?,
+        HandshakeError::UnsupportedMintExtension
+    );
+    require!(
+        transfer_hook_program(mint)?.is_none(),
+        HandshakeError::UnsupportedMintExtension
+    );
+
+    Ok(())
+}
+
+/// Move a Token-2022 account's withheld transfer fees to its mint, where the fee
+/// authority collects them. A token account still holding withheld fees cannot be
+/// closed. Returns the amount harvested; accounts without withheld fees are a no-op.
+pub fn harvest_withheld_fees<'info>(
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_account: AccountInfo<'info>,
+) -> Result<u64> {
+    if *token_account.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let withheld_amount = {
+        let data = token_account.try_borrow_data()?;
+        let account = StateWithExtensions::<Account>::unpack(&data)?;
+        account
+            .get_extension::<TransferFeeAmount>()
+            .map_or(0, |transfer_fee_amount| u64::from(transfer_fee_amount.withheld_amount))
+    };
+
+    if withheld_amount > 0 {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                token_program.clone(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: token_program,
+                    mint,
+                },
+            ),
+            vec![token_account],
+        )?;
+    }
+
+    Ok(withheld_amount)
+}
+
+/// `transfer_checked` that also forwards a Token-2022 transfer hook's extra
+/// accounts, looked up by key in `additional_accounts` (the instruction's remaining
+/// accounts). Mints without a hook transfer exactly like `token_interface`.
+pub fn transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    spl_token_2022::onchain::invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        additional_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -1 +1,19 @@
 pub const ACCOUNT_SEED: &[u8] = b"account";
+
+/// Minimum guardian recovery timelock (1 day) — gives the owner time to cancel
+pub const MIN_RECOVERY_DELAY: i64 = 24 * 60 * 60;
+
+pub const PENDING_TRANSFER_SEED: &[u8] = b"pending_transfer";
+
+pub const RECURRING_PAYMENT_SEED: &[u8] = b"recurring_payment";
+
+pub const DRIFT_CLEANUP_SEED: &[u8] = b"drift_cleanup";
+
+/// Shortest allowed interval between recurring payments (1 hour)
+pub const MIN_RECURRING_INTERVAL: i64 = 60 * 60;
+
+/// Basis-point denominator for the liquidity buffer (10_000 = 100%)
+pub const MAX_BPS: u16 = 10_000;
+
+/// Longest an operator's transfer request may wait for owner approval (30 days)
+pub const MAX_PENDING_TRANSFER_TTL: i64 = 30 * 24 * 60 * 60;
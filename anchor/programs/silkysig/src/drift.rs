@@ -0,0 +1,593 @@
+use anchor_lang::prelude::*;
+use crate::{debug_msg, state::*, errors::*, constants::*, yield_adapter::{AdapterContext, YieldAdapter}};
+
+/// Number of Drift accounts every instruction expects at the start of its remaining accounts
+pub const DRIFT_ACCOUNTS_LEN: usize = 8;
+
+/// Drift's global state PDA (`["drift_state"]`)
+pub const STATE: Pubkey = pubkey!("5zpq7DvB6UdFFvpmBPspGPNfUGoBRRCE2HHg5u3gxcsN");
+
+/// Drift's vault signer PDA (`["drift_signer"]`)
+pub const SIGNER: Pubkey = pubkey!("JCNCMFXo5M5qwUPg2Utu1u6YWp3MbygxqBsBeXXJfrw");
+
+pub fn user_address(authority: &Pubkey, sub_account_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"user", authority.as_ref(), &sub_account_id.to_le_bytes()],
+        &drift_cpi::ID,
+    )
+    .0
+}
+
+pub fn user_stats_address(authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user_stats", authority.as_ref()], &drift_cpi::ID).0
+}
+
+pub fn spot_market_address(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[b"spot_market", &market_index.to_le_bytes()], &drift_cpi::ID).0
+}
+
+pub fn spot_market_vault_address(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"spot_market_vault", &market_index.to_le_bytes()],
+        &drift_cpi::ID,
+    )
+    .0
+}
+
+impl DriftBumps {
+    /// Derive the bumps for `authority` in `market_index` (once, when they are recorded).
+    pub fn find(authority: &Pubkey, market_index: u16) -> Self {
+        let market_index = market_index.to_le_bytes();
+        Self {
+            user_stats: Pubkey::find_program_address(&[b"user_stats", authority.as_ref()], &drift_cpi::ID).1,
+            spot_market: Pubkey::find_program_address(&[b"spot_market", &market_index], &drift_cpi::ID).1,
+            spot_market_vault: Pubkey::find_program_address(
+                &[b"spot_market_vault", &market_index],
+                &drift_cpi::ID,
+            )
+            .1,
+        }
+    }
+}
+
+/// Whether `key` is the Drift PDA for `seeds` and the recorded `bump` (one hash, no bump search).
+fn is_pda(key: &Pubkey, seeds: &[&[u8]], bump: u8) -> bool {
+    let bump = [bump];
+    let mut seeds = seeds.to_vec();
+    seeds.push(&bump);
+    Pubkey::create_program_address(&seeds, &drift_cpi::ID).is_ok_and(|pda| pda == *key)
+}
+
+/// Check that `spot_market` is the account's Drift spot market for `market_index`.
+pub fn require_spot_market(
+    silk_account: &SilkAccount,
+    spot_market: &AccountInfo,
+    market_index: u16,
+) -> Result<()> {
+    require!(
+        is_pda(
+            spot_market.key,
+            &[b"spot_market", &market_index.to_le_bytes()],
+            silk_account.drift_bumps.spot_market,
+        ),
+        SilkysigError::InvalidDriftSpotMarket
+    );
+    Ok(())
+}
+
+/// The Drift account set shared by every instruction that touches the account's
+/// Drift position, in remaining-accounts order:
+/// state, user, user_stats, spot_market_vault, drift_signer, program, oracle, spot_market.
+pub struct DriftAccounts<'a, 'info> {
+    pub state: &'a AccountInfo<'info>,
+    pub user: &'a AccountInfo<'info>,
+    pub user_stats: &'a AccountInfo<'info>,
+    pub spot_market_vault: &'a AccountInfo<'info>,
+    pub drift_signer: &'a AccountInfo<'info>,
+    pub program: &'a AccountInfo<'info>,
+    pub oracle: &'a AccountInfo<'info>,
+    pub spot_market: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> DriftAccounts<'a, 'info> {
+    /// Read the Drift accounts for `market_index` from the start of `remaining_accounts`,
+    /// checking each against its expected address using the account's recorded
+    /// `drift_bumps`. The oracle is left to Drift, which checks it against the spot market.
+    pub fn load(
+        silk_account: &Account<'info, SilkAccount>,
+        remaining_accounts: &'a [AccountInfo<'info>],
+        market_index: u16,
+    ) -> Result<Self> {
+        require!(
+            remaining_accounts.len() >= DRIFT_ACCOUNTS_LEN,
+            SilkysigError::MissingDriftAccounts
+        );
+
+        let accounts = Self {
+            state: &remaining_accounts[0],
+            user: &remaining_accounts[1],
+            user_stats: &remaining_accounts[2],
+            spot_market_vault: &remaining_accounts[3],
+            drift_signer: &remaining_accounts[4],
+            program: &remaining_accounts[5],
+            oracle: &remaining_accounts[6],
+            spot_market: &remaining_accounts[7],
+        };
+
+        require!(
+            accounts.program.key() == drift_cpi::ID,
+            SilkysigError::InvalidDriftProgram
+        );
+        require!(
+            Some(accounts.user.key()) == silk_account.drift_user,
+            SilkysigError::InvalidDriftUser
+        );
+        require!(
+            accounts.state.key() == STATE,
+            SilkysigError::InvalidDriftState
+        );
+        require!(
+            is_pda(
+                accounts.user_stats.key,
+                &[b"user_stats", silk_account.key().as_ref()],
+                silk_account.drift_bumps.user_stats,
+            ),
+            SilkysigError::InvalidDriftUserStats
+        );
+        require_spot_market(silk_account, accounts.spot_market, market_index)?;
+        require!(
+            is_pda(
+                accounts.spot_market_vault.key,
+                &[b"spot_market_vault", &market_index.to_le_bytes()],
+                silk_account.drift_bumps.spot_market_vault,
+            ),
+            SilkysigError::InvalidDriftSpotMarketVault
+        );
+        require!(
+            accounts.drift_signer.key() == SIGNER,
+            SilkysigError::InvalidDriftSigner
+        );
+
+        Ok(accounts)
+    }
+}
+
+/// Deposit `amount` from the account's ATA into its Drift position in `market_index`.
+///
+/// Expects the `DriftAccounts` for `market_index` at the start of `remaining_accounts`.
+pub fn deposit<'info>(
+    silk_account: &Account<'info, SilkAccount>,
+    remaining_accounts: &[AccountInfo<'info>],
+    account_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    market_index: u16,
+    amount: u64,
+) -> Result<()> {
+    let drift = DriftAccounts::load(silk_account, remaining_accounts, market_index)?;
+    MarketConditions::load(drift.state, drift.spot_market)?.check(MarketAction::Deposit)?;
+
+    let creator_key = silk_account.creator;
+    let mint_key = silk_account.mint;
+    let account_seeds = &[
+        ACCOUNT_SEED,
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        &[silk_account.bump],
+    ];
+    let signer_seeds = &[&account_seeds[..]];
+
+    let deposit_accounts = drift_cpi::cpi::accounts::Deposit {
+        state: drift.state.to_account_info(),
+        user: drift.user.to_account_info(),
+        user_stats: drift.user_stats.to_account_info(),
+        authority: silk_account.to_account_info(),
+        spot_market_vault: drift.spot_market_vault.to_account_info(),
+        user_token_account: account_token_account,
+        token_program,
+    };
+
+    let drift_remaining = vec![
+        drift.oracle.to_account_info(),
+        drift.spot_market.to_account_info(),
+    ];
+
+    let deposit_ctx = CpiContext::new_with_signer(
+        drift.program.to_account_info(),
+        deposit_accounts,
+        signer_seeds,
+    )
+    .with_remaining_accounts(drift_remaining);
+
+    match drift_cpi::cpi::deposit(deposit_ctx, market_index, amount, false) {
+        Ok(_) => Ok(()),
+        Err(_e) => {
+            debug_msg!("Drift deposit failed: {:?}", _e);
+            Err(SilkysigError::DriftDepositFailed.into())
+        }
+    }
+}
+
+/// Withdraw `amount` from the account's Drift position into its ATA.
+///
+/// Expects the account's `DriftAccounts` at the start of `remaining_accounts`.
+pub fn withdraw<'info>(
+    silk_account: &Account<'info, SilkAccount>,
+    remaining_accounts: &[AccountInfo<'info>],
+    account_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+    reduce_only: bool,
+) -> Result<()> {
+    let market_index = silk_account
+        .drift_market_index
+        .ok_or(SilkysigError::DriftNotInitialized)?;
+    let drift = DriftAccounts::load(silk_account, remaining_accounts, market_index)?;
+    MarketConditions::load(drift.state, drift.spot_market)?.check(MarketAction::Withdraw)?;
+
+    let creator_key = silk_account.creator;
+    let mint_key = silk_account.mint;
+    let account_seeds = &[
+        ACCOUNT_SEED,
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        &[silk_account.bump],
+    ];
+    let signer_seeds = &[&account_seeds[..]];
+
+    let withdraw_accounts = drift_cpi::cpi::accounts::Withdraw {
+        state: drift.state.to_account_info(),
+        user: drift.user.to_account_info(),
+        user_stats: drift.user_stats.to_account_info(),
+        authority: silk_account.to_account_info(),
+        spot_market_vault: drift.spot_market_vault.to_account_info(),
+        drift_signer: drift.drift_signer.to_account_info(),
+        user_token_account: account_token_account,
+        token_program,
+    };
+
+    let drift_remaining = vec![
+        drift.oracle.to_account_info(),
+        drift.spot_market.to_account_info(),
+    ];
+
+    let withdraw_ctx = CpiContext::new_with_signer(
+        drift.program.to_account_info(),
+        withdraw_accounts,
+        signer_seeds,
+    )
+    .with_remaining_accounts(drift_remaining);
+
+    match drift_cpi::cpi::withdraw(withdraw_ctx, market_index, amount, reduce_only) {
+        Ok(_) => Ok(()),
+        Err(_e) => {
+            debug_msg!("Drift withdraw failed: {:?}", _e);
+            Err(SilkysigError::DriftWithdrawFailed.into())
+        }
+    }
+}
+
+/// Drift `ExchangeStatus` bits that stop deposits and withdrawals exchange-wide
+const EXCHANGE_DEPOSIT_PAUSED: u8 = 1 << 0;
+const EXCHANGE_WITHDRAW_PAUSED: u8 = 1 << 1;
+/// Drift `SpotOperation` bits in `SpotMarket.paused_operations`
+const SPOT_DEPOSIT_PAUSED: u8 = 1 << 2;
+const SPOT_WITHDRAW_PAUSED: u8 = 1 << 3;
+
+/// Side of a Drift spot-market move, for `MarketConditions::check`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarketAction {
+    Deposit,
+    Withdraw,
+}
+
+/// What Drift's state and spot market say about moving funds right now.
+///
+/// Checked before every deposit/withdraw CPI so the causes agents handle differently
+/// (paused market, stale oracle) fail with their own `SilkysigError`. Anything Drift
+/// rejects past these checks (e.g. insufficient collateral) aborts the transaction
+/// with Drift's own custom error code, which clients decode against Drift's IDL.
+#[derive(Clone, Copy, Debug)]
+pub struct MarketConditions {
+    pub exchange_status: u8,
+    pub market_status: drift_cpi::MarketStatus,
+    pub paused_operations: u8,
+    /// Slots the oracle was behind at the market's last update
+    pub oracle_delay: i64,
+    /// Drift's `slots_before_stale_for_margin` guard rail
+    pub max_oracle_delay: i64,
+}
+
+impl MarketConditions {
+    /// Read the conditions from Drift's state and the spot market (both already address-checked).
+    pub fn load(state: &AccountInfo, spot_market: &AccountInfo) -> Result<Self> {
+        require!(
+            state.owner == &drift_cpi::ID && spot_market.owner == &drift_cpi::ID,
+            SilkysigError::InvalidDriftProgram
+        );
+        let state = Box::new(drift_cpi::State::try_deserialize(
+            &mut &state.try_borrow_data()?[..],
+        )?);
+        let spot_market = Box::new(drift_cpi::SpotMarket::try_deserialize(
+            &mut &spot_market.try_borrow_data()?[..],
+        )?);
+
+        Ok(Self {
+            exchange_status: state.exchange_status,
+            market_status: spot_market.status,
+            paused_operations: spot_market.paused_operations,
+            oracle_delay: spot_market.historical_oracle_data.last_oracle_delay,
+            max_oracle_delay: state.oracle_guard_rails.validity.slots_before_stale_for_margin,
+        })
+    }
+
+    /// Fail with the reason Drift would refuse `action`, if any.
+    pub fn check(&self, action: MarketAction) -> Result<()> {
+        use drift_cpi::MarketStatus::*;
+
+        match action {
+            MarketAction::Deposit => require!(
+                self.exchange_status & EXCHANGE_DEPOSIT_PAUSED == 0
+                    && self.paused_operations & SPOT_DEPOSIT_PAUSED == 0
+                    && !matches!(
+                        self.market_status,
+                        Initialized | ReduceOnly | Settlement | Delisted
+                    ),
+                SilkysigError::DriftDepositsPaused
+            ),
+            MarketAction::Withdraw => {
+                require!(
+                    self.exchange_status & EXCHANGE_WITHDRAW_PAUSED == 0
+                        && self.paused_operations & SPOT_WITHDRAW_PAUSED == 0
+                        && !matches!(self.market_status, Initialized | WithdrawPaused),
+                    SilkysigError::DriftWithdrawalsPaused
+                );
+                // Drift prices withdrawals against the oracle for the margin check
+                require!(
+                    self.oracle_delay <= self.max_oracle_delay,
+                    SilkysigError::DriftOracleStale
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Offset of `User.spot_positions` (discriminator + authority + delegate + name)
+const USER_SPOT_POSITIONS_OFFSET: usize = 8 + 32 + 32 + 32;
+/// Serialized size of one `SpotPosition`
+const SPOT_POSITION_SIZE: usize = 40;
+/// Number of spot position slots on a Drift `User`
+const MAX_SPOT_POSITIONS: usize = 8;
+/// Drift's SPOT_BALANCE_PRECISION (1e9) * SPOT_CUMULATIVE_INTEREST_PRECISION (1e10) exponent
+const SPOT_INTEREST_PRECISION_EXP: u32 = 19;
+
+/// Token amount currently deposited in `market_index` by a Drift user,
+/// using the spot market's cumulative deposit interest as of its last update.
+pub fn deposit_token_amount(
+    drift_user: &AccountInfo,
+    drift_spot_market: &AccountInfo,
+    market_index: u16,
+) -> Result<u64> {
+    require!(
+        drift_user.owner == &drift_cpi::ID && drift_spot_market.owner == &drift_cpi::ID,
+        SilkysigError::InvalidDriftProgram
+    );
+
+    let spot_market = drift_cpi::SpotMarket::try_deserialize(
+        &mut &drift_spot_market.try_borrow_data()?[..],
+    )?;
+    require!(
+        spot_market.market_index == market_index,
+        SilkysigError::InvalidDriftSpotMarket
+    );
+
+    // Drift's User is too large to deserialize on the stack — decode only the spot positions
+    let user_data = drift_user.try_borrow_data()?;
+    require!(
+        user_data.len() >= USER_SPOT_POSITIONS_OFFSET + MAX_SPOT_POSITIONS * SPOT_POSITION_SIZE
+            && user_data[..8] == *drift_cpi::User::DISCRIMINATOR,
+        SilkysigError::InvalidDriftUser
+    );
+
+    let mut scaled_balance = 0u64;
+    for i in 0..MAX_SPOT_POSITIONS {
+        let start = USER_SPOT_POSITIONS_OFFSET + i * SPOT_POSITION_SIZE;
+        let position = drift_cpi::SpotPosition::deserialize(
+            &mut &user_data[start..start + SPOT_POSITION_SIZE],
+        )?;
+        if position.market_index == market_index
+            && position.scaled_balance > 0
+            && matches!(position.balance_type, drift_cpi::SpotBalanceType::Deposit)
+        {
+            scaled_balance = position.scaled_balance;
+            break;
+        }
+    }
+
+    let precision_decrease = 10u128
+        .checked_pow(SPOT_INTEREST_PRECISION_EXP.saturating_sub(spot_market.decimals))
+        .ok_or(SilkysigError::MathOverflow)?;
+    let amount = (scaled_balance as u128)
+        .checked_mul(spot_market.cumulative_deposit_interest)
+        .ok_or(SilkysigError::MathOverflow)?
+        / precision_decrease;
+
+    u64::try_from(amount).map_err(|_| SilkysigError::MathOverflow.into())
+}
+
+/// Drift spot-market venue behind `YieldAdapter`.
+///
+/// Reads the account's `DriftAccounts` from the start of remaining accounts.
+pub struct DriftAdapter<'a, 'info> {
+    ctx: AdapterContext<'a, 'info>,
+    market_index: u16,
+}
+
+impl<'a, 'info> DriftAdapter<'a, 'info> {
+    pub fn new(ctx: AdapterContext<'a, 'info>) -> Result<Self> {
+        let market_index = ctx
+            .silk_account
+            .drift_market_index
+            .ok_or(SilkysigError::DriftNotInitialized)?;
+        Ok(Self { ctx, market_index })
+    }
+}
+
+impl<'a, 'info> YieldAdapter<'info> for DriftAdapter<'a, 'info> {
+    fn deposit(&self, amount: u64) -> Result<()> {
+        deposit(
+            self.ctx.silk_account,
+            self.ctx.remaining_accounts,
+            self.ctx.account_token_account.clone(),
+            self.ctx.token_program.clone(),
+            self.market_index,
+            amount,
+        )
+    }
+
+    fn withdraw(&self, amount: u64) -> Result<()> {
+        withdraw(
+            self.ctx.silk_account,
+            self.ctx.remaining_accounts,
+            self.ctx.account_token_account.clone(),
+            self.ctx.token_program.clone(),
+            amount,
+            false,
+        )
+    }
+
+    fn withdraw_all(&self) -> Result<()> {
+        // u64::MAX + reduce_only=true tells Drift to withdraw the full balance
+        // without risking InsufficientCollateral errors
+        withdraw(
+            self.ctx.silk_account,
+            self.ctx.remaining_accounts,
+            self.ctx.account_token_account.clone(),
+            self.ctx.token_program.clone(),
+            u64::MAX,
+            true,
+        )
+    }
+
+    fn position_value(&self) -> Result<u64> {
+        // Read-only: only the user and spot market are needed
+        let remaining = self.ctx.remaining_accounts;
+        require!(
+            remaining.len() >= DRIFT_ACCOUNTS_LEN,
+            SilkysigError::MissingDriftAccounts
+        );
+        require!(
+            Some(remaining[1].key()) == self.ctx.silk_account.drift_user,
+            SilkysigError::InvalidDriftUser
+        );
+        require_spot_market(self.ctx.silk_account, &remaining[7], self.market_index)?;
+        deposit_token_amount(&remaining[1], &remaining[7], self.market_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_pdas_match_derivation() {
+        assert_eq!(STATE, Pubkey::find_program_address(&[b"drift_state"], &drift_cpi::ID).0);
+        assert_eq!(SIGNER, Pubkey::find_program_address(&[b"drift_signer"], &drift_cpi::ID).0);
+    }
+
+    #[test]
+    fn recorded_bumps_verify_without_search() {
+        let authority = Pubkey::new_unique();
+        let bumps = DriftBumps::find(&authority, 0);
+
+        assert!(is_pda(
+            &user_stats_address(&authority),
+            &[b"user_stats", authority.as_ref()],
+            bumps.user_stats,
+        ));
+        assert!(is_pda(
+            &spot_market_address(0),
+            &[b"spot_market", &0u16.to_le_bytes()],
+            bumps.spot_market,
+        ));
+        assert!(is_pda(
+            &spot_market_vault_address(0),
+            &[b"spot_market_vault", &0u16.to_le_bytes()],
+            bumps.spot_market_vault,
+        ));
+        // Another authority's user stats do not pass for this account
+        assert!(!is_pda(
+            &user_stats_address(&Pubkey::new_unique()),
+            &[b"user_stats", authority.as_ref()],
+            bumps.user_stats,
+        ));
+    }
+
+    fn open_market() -> MarketConditions {
+        MarketConditions {
+            exchange_status: 0,
+            market_status: drift_cpi::MarketStatus::Active,
+            paused_operations: 0,
+            oracle_delay: 2,
+            max_oracle_delay: 120,
+        }
+    }
+
+    fn error_code(result: Result<()>) -> u32 {
+        match result.unwrap_err() {
+            Error::AnchorError(e) => e.error_code_number,
+            e => panic!("unexpected error {e:?}"),
+        }
+    }
+
+    #[test]
+    fn open_market_allows_both_sides() {
+        assert!(open_market().check(MarketAction::Deposit).is_ok());
+        assert!(open_market().check(MarketAction::Withdraw).is_ok());
+    }
+
+    #[test]
+    fn paused_operations_fail_with_their_own_error() {
+        let deposits_paused = MarketConditions { paused_operations: SPOT_DEPOSIT_PAUSED, ..open_market() };
+        assert_eq!(
+            error_code(deposits_paused.check(MarketAction::Deposit)),
+            u32::from(SilkysigError::DriftDepositsPaused)
+        );
+        assert!(deposits_paused.check(MarketAction::Withdraw).is_ok());
+
+        let exchange_paused = MarketConditions { exchange_status: EXCHANGE_WITHDRAW_PAUSED, ..open_market() };
+        assert_eq!(
+            error_code(exchange_paused.check(MarketAction::Withdraw)),
+            u32::from(SilkysigError::DriftWithdrawalsPaused)
+        );
+        assert!(exchange_paused.check(MarketAction::Deposit).is_ok());
+    }
+
+    #[test]
+    fn market_status_gates_each_side() {
+        let reduce_only = MarketConditions { market_status: drift_cpi::MarketStatus::ReduceOnly, ..open_market() };
+        assert_eq!(
+            error_code(reduce_only.check(MarketAction::Deposit)),
+            u32::from(SilkysigError::DriftDepositsPaused)
+        );
+        // Funds can still leave a reduce-only market
+        assert!(reduce_only.check(MarketAction::Withdraw).is_ok());
+
+        let withdraw_paused =
+            MarketConditions { market_status: drift_cpi::MarketStatus::WithdrawPaused, ..open_market() };
+        assert_eq!(
+            error_code(withdraw_paused.check(MarketAction::Withdraw)),
+            u32::from(SilkysigError::DriftWithdrawalsPaused)
+        );
+    }
+
+    #[test]
+    fn stale_oracle_blocks_withdrawals_only() {
+        let stale = MarketConditions { oracle_delay: 121, ..open_market() };
+        assert_eq!(
+            error_code(stale.check(MarketAction::Withdraw)),
+            u32::from(SilkysigError::DriftOracleStale)
+        );
+        assert!(stale.check(MarketAction::Deposit).is_ok());
+    }
+}
@@ -46,4 +46,127 @@ pub enum SilkysigError {
 
     #[msg("Failed to delete Drift user account")]
     DriftDeleteUserFailed,
+
+    #[msg("Not enough owner approvals for this action")]
+    InsufficientOwnerApprovals,
+
+    #[msg("Invalid owner set configuration")]
+    InvalidOwnerSet,
+
+    #[msg("Invalid new owner")]
+    InvalidNewOwner,
+
+    #[msg("No ownership transfer pending")]
+    NoPendingOwnershipTransfer,
+
+    #[msg("Invalid guardian configuration")]
+    InvalidGuardianSet,
+
+    #[msg("Recovery guardians not configured")]
+    RecoveryNotConfigured,
+
+    #[msg("Not enough guardian approvals for recovery")]
+    InsufficientGuardianApprovals,
+
+    #[msg("A recovery is already pending")]
+    RecoveryAlreadyPending,
+
+    #[msg("No recovery pending")]
+    NoRecoveryPending,
+
+    #[msg("Recovery timelock has not elapsed")]
+    RecoveryTimelockActive,
+
+    #[msg("Invalid pending transfer expiry")]
+    InvalidExpiry,
+
+    #[msg("Pending transfer has expired")]
+    PendingTransferExpired,
+
+    #[msg("Pause guardian can only pause")]
+    GuardianCannotUnpause,
+
+    #[msg("Silk account does not match seed derivation")]
+    InvalidSilkAccount,
+
+    #[msg("Drift user is still attached to the Silk account")]
+    DriftUserStillActive,
+
+    #[msg("Invalid Drift spot market")]
+    InvalidDriftSpotMarket,
+
+    #[msg("Drift not initialized for this account")]
+    DriftNotInitialized,
+
+    #[msg("Yield-only operator cannot spend principal")]
+    ExceedsAvailableYield,
+
+    #[msg("Another yield adapter is already active")]
+    YieldAdapterActive,
+
+    #[msg("No yield adapter is active")]
+    NoYieldAdapter,
+
+    #[msg("Invalid liquidity buffer: bps above 10000 or both bps and amount set")]
+    InvalidLiquidityBuffer,
+
+    #[msg("Invalid Drift state account")]
+    InvalidDriftState,
+
+    #[msg("Invalid Drift user stats account")]
+    InvalidDriftUserStats,
+
+    #[msg("Invalid Drift spot market vault")]
+    InvalidDriftSpotMarketVault,
+
+    #[msg("Invalid Drift signer account")]
+    InvalidDriftSigner,
+
+    #[msg("Silk accounts hold different mints")]
+    MintMismatch,
+
+    #[msg("Invalid recurring payment schedule")]
+    InvalidRecurringSchedule,
+
+    #[msg("Recurring payment is not due yet")]
+    RecurringPaymentNotDue,
+
+    #[msg("Recurring payment schedule has ended")]
+    RecurringPaymentEnded,
+
+    #[msg("Operator lacks the permission for this action")]
+    OperatorNotPermitted,
+
+    #[msg("Unknown operator permission bits")]
+    InvalidOperatorPermissions,
+
+    #[msg("Mint has a Token-2022 extension Silk accounts do not support")]
+    UnsupportedMintExtension,
+
+    #[msg("Closing with a Drift user attached requires the drift_cleanup record")]
+    MissingDriftCleanup,
+
+    #[msg("Drift is not accepting deposits for this spot market")]
+    DriftDepositsPaused,
+
+    #[msg("Drift is not allowing withdrawals from this spot market")]
+    DriftWithdrawalsPaused,
+
+    #[msg("Drift oracle for this spot market is stale")]
+    DriftOracleStale,
+
+    #[msg("Operators can pause but not unpause")]
+    OperatorCannotUnpause,
+
+    #[msg("Drift cannot hold a mint with a transfer hook program set")]
+    DriftHookedMint,
+
+    #[msg("Another detached Drift user must be cleaned up first")]
+    DetachedDriftUserPending,
+
+    #[msg("Cancel the account's recurring payments before closing it")]
+    RecurringPaymentsOutstanding,
+
+    #[msg("Recurring payment belongs to an earlier account at this address")]
+    StaleRecurringPayment,
 }
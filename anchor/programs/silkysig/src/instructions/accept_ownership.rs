@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    let new_owner = ctx.accounts.new_owner.key();
+
+    require!(
+        account.pending_owner == Some(new_owner),
+        SilkysigError::NoPendingOwnershipTransfer
+    );
+
+    let previous_owner = account.owner;
+    account.set_owner(new_owner);
+
+    emit!(OwnershipTransferred {
+        account: account.key(),
+        previous_owner,
+        new_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwnership<'info> {
+    /// The proposed owner
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub account: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
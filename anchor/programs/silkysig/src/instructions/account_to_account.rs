@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    TransferChecked, Mint, TokenAccount, TokenInterface,
+};
+use handshake::token_extensions;
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
+
+/// Move funds from one Silk account to another of the same mint without leaving
+/// the program: a withdrawal for the source and a deposit for the destination.
+///
+/// Remaining accounts: the source's venue accounts (if it has a yield adapter),
+/// then the destination's, then any co-owner signatures for the source, then any
+/// transfer-hook accounts for the mint.
+pub fn account_to_account<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, AccountToAccount<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SilkysigError::AmountMustBePositive);
+
+    let source = &mut ctx.accounts.source_account;
+    let destination = &mut ctx.accounts.destination_account;
+    let signer_key = ctx.accounts.signer.key();
+
+    let source_len = yield_adapter::accounts_len(source.yield_adapter);
+    let destination_len = yield_adapter::accounts_len(destination.yield_adapter);
+    require!(
+        ctx.remaining_accounts.len() >= source_len + destination_len,
+        SilkysigError::MissingDriftAccounts
+    );
+    let source_remaining = ctx.remaining_accounts;
+    let destination_remaining = &ctx.remaining_accounts[source_len..];
+
+    let source_adapter = yield_adapter::load(AdapterContext {
+        silk_account: source,
+        remaining_accounts: source_remaining,
+        account_token_account: ctx.accounts.source_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })?;
+
+    // Value before the withdrawal decides how much of it is yield vs principal
+    let current_value = yield_adapter::account_value(
+        source_adapter.as_deref(),
+        ctx.accounts.source_token_account.amount,
+    )?;
+
+    let is_owner = source.authorize_spend(
+        &signer_key,
+        OPERATOR_TRANSFER,
+        amount,
+        current_value,
+        ctx.remaining_accounts,
+    )?;
+
+    // Pay from the source's liquidity buffer; only the shortfall comes out of its venue
+    let shortfall = amount.saturating_sub(ctx.accounts.source_token_account.amount);
+    if let Some(adapter) = &source_adapter {
+        if shortfall > 0 {
+            adapter.withdraw(shortfall)?;
+        }
+    }
+    // Release the adapter's borrow of the account before updating it
+    drop(source_adapter);
+
+    let creator_key = source.creator;
+    let mint_key = source.mint;
+    let account_seeds = &[
+        ACCOUNT_SEED,
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        &[source.bump],
+    ];
+    let signer_seeds = &[&account_seeds[..]];
+
+    let destination_before = ctx.accounts.destination_token_account.amount;
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.source_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.destination_token_account.to_account_info(),
+        authority: source.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_accounts,
+        signer_seeds,
+    );
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let (from_yield, from_principal) = source.record_withdrawal(amount, current_value)?;
+
+    // Destination side mirrors `deposit`: book what actually arrived (net of any
+    // Token-2022 transfer fee), top up its buffer, forward the rest to its venue
+    ctx.accounts.destination_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .destination_token_account
+        .amount
+        .checked_sub(destination_before)
+        .ok_or(SilkysigError::MathOverflow)?;
+    let destination_principal = destination
+        .principal_balance
+        .checked_add(received)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    if let Some(adapter) = yield_adapter::load(AdapterContext {
+        silk_account: destination,
+        remaining_accounts: destination_remaining,
+        account_token_account: ctx.accounts.destination_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })? {
+        let buffer_target = destination.buffer_target(destination_principal);
+        let to_venue = ctx
+            .accounts
+            .destination_token_account
+            .amount
+            .saturating_sub(buffer_target)
+            .min(received);
+        if to_venue > 0 {
+            adapter.deposit(to_venue)?;
+        }
+    }
+
+    destination.principal_balance = destination_principal;
+
+    emit!(AccountToAccountTransferred {
+        source: source.key(),
+        destination: destination.key(),
+        signer: signer_key,
+        mint: mint_key,
+        amount,
+        from_yield,
+        from_principal,
+        is_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AccountToAccount<'info> {
+    /// Owner or operator of the source account
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, source_account.creator.as_ref(), source_account.mint.as_ref()],
+        bump = source_account.bump,
+    )]
+    pub source_account: Box<Account<'info, SilkAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, destination_account.creator.as_ref(), destination_account.mint.as_ref()],
+        bump = destination_account.bump,
+        constraint = destination_account.mint == source_account.mint @ SilkysigError::MintMismatch,
+        constraint = destination_account.key() != source_account.key() @ SilkysigError::InvalidSilkAccount,
+    )]
+    pub destination_account: Box<Account<'info, SilkAccount>>,
+
+    /// Mint must match both accounts' mint
+    #[account(
+        constraint = mint.key() == source_account.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Source account PDA's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = source_account,
+        associated_token::token_program = token_program
+    )]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Destination account PDA's token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = destination_account,
+        associated_token::token_program = token_program
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct AccountToAccountTransferred {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub signer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub from_yield: u64,
+    pub from_principal: u64,
+    pub is_owner: bool,
+}
@@ -1,19 +1,26 @@
 use anchor_lang::prelude::*;
 use crate::{state::*, errors::*, constants::*};
 
-pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey, per_tx_limit: Option<u64>) -> Result<()> {
+pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey, per_tx_limit: Option<u64>, yield_only: bool, permissions: u8) -> Result<()> {
     let limit = per_tx_limit.unwrap_or(u64::MAX);
 
     let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
     let count = account.operator_count as usize;
 
     require!(count < MAX_OPERATORS, SilkysigError::MaxOperatorsReached);
     require!(account.find_operator(&operator).is_none(), SilkysigError::OperatorAlreadyExists);
+    require!(
+        permissions & !OPERATOR_ALL_PERMISSIONS == 0,
+        SilkysigError::InvalidOperatorPermissions
+    );
 
     account.operators[count] = OperatorSlot {
         pubkey: operator,
         per_tx_limit: limit,
-        _reserved: [0u8; 24],
+        yield_only,
+        permissions,
+        _reserved: [0u8; 22],
     };
     account.operator_count += 1;
 
@@ -22,6 +29,8 @@ pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey, per_tx_limit: O
         owner: account.owner,
         operator,
         per_tx_limit: limit,
+        yield_only,
+        permissions,
         slot_index: count as u8,
     });
 
@@ -30,14 +39,14 @@ pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey, per_tx_limit: O
 
 #[derive(Accounts)]
 pub struct AddOperator<'info> {
+    /// Owner or co-owner — further co-owner signatures go in remaining accounts
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub signer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [ACCOUNT_SEED, owner.key().as_ref()],
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
         bump = silk_account.bump,
-        has_one = owner,
     )]
     pub silk_account: Box<Account<'info, SilkAccount>>,
 }
@@ -48,5 +57,7 @@ pub struct OperatorAdded {
     pub owner: Pubkey,
     pub operator: Pubkey,
     pub per_tx_limit: u64,
+    pub yield_only: bool,
+    pub permissions: u8,
     pub slot_index: u8,
 }
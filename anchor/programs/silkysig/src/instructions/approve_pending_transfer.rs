@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        TransferChecked, Mint, TokenAccount, TokenInterface,
+    },
+};
+use handshake::token_extensions;
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
+
+/// Owner approves a queued operator transfer and executes it immediately.
+pub fn approve_pending_transfer<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ApprovePendingTransfer<'info>>,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+
+    let pending = &ctx.accounts.pending_transfer;
+    let clock = Clock::get()?;
+    require!(
+        !pending.is_expired(clock.unix_timestamp),
+        SilkysigError::PendingTransferExpired
+    );
+
+    let amount = pending.amount;
+
+    let adapter = yield_adapter::load(AdapterContext {
+        silk_account: account,
+        remaining_accounts: ctx.remaining_accounts,
+        account_token_account: ctx.accounts.account_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })?;
+
+    // Value before the withdrawal decides how much of it is yield vs principal
+    let current_value = yield_adapter::account_value(
+        adapter.as_deref(),
+        ctx.accounts.account_token_account.amount,
+    )?;
+
+    // Pay from the liquidity buffer; only the shortfall comes out of the venue
+    let shortfall = amount.saturating_sub(ctx.accounts.account_token_account.amount);
+    if let Some(adapter) = &adapter {
+        if shortfall > 0 {
+            adapter.withdraw(shortfall)?;
+        }
+    }
+    // Release the adapter's borrow of the account before updating it
+    drop(adapter);
+
+    // Transfer tokens from account PDA's ATA to recipient's ATA
+    let creator_key = account.creator;
+    let mint_key = account.mint;
+    let account_seeds = &[
+        ACCOUNT_SEED,
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        &[account.bump],
+    ];
+    let signer_seeds = &[&account_seeds[..]];
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.account_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_accounts,
+        signer_seeds,
+    );
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let (from_yield, from_principal) = account.record_withdrawal(amount, current_value)?;
+
+    emit!(PendingTransferApproved {
+        account: account.key(),
+        pending_transfer: pending.key(),
+        id: pending.id,
+        approver: ctx.accounts.signer.key(),
+        operator: pending.requester,
+        recipient: pending.recipient,
+        mint: ctx.accounts.mint.key(),
+        amount,
+        from_yield,
+        from_principal,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApprovePendingTransfer<'info> {
+    /// Owner or co-owner — pays for recipient ATA init if needed
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// The request being approved (closed to the requesting operator)
+    #[account(
+        mut,
+        seeds = [
+            PENDING_TRANSFER_SEED,
+            silk_account.key().as_ref(),
+            pending_transfer.id.to_le_bytes().as_ref(),
+        ],
+        bump = pending_transfer.bump,
+        has_one = requester,
+        has_one = recipient,
+        close = requester,
+    )]
+    pub pending_transfer: Box<Account<'info, PendingTransfer>>,
+
+    /// CHECK: Requesting operator — receives the request's rent. Validated by has_one.
+    #[account(mut)]
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        constraint = mint.key() == silk_account.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Account PDA's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Recipient address — validated by has_one, used as ATA derivation authority
+    pub recipient: AccountInfo<'info>,
+
+    /// Recipient's token account — initialized if needed, signer pays rent
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PendingTransferApproved {
+    pub account: Pubkey,
+    pub pending_transfer: Pubkey,
+    pub id: u64,
+    pub approver: Pubkey,
+    pub operator: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub from_yield: u64,
+    pub from_principal: u64,
+}
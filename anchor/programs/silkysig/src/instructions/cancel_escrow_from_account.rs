@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use handshake::program::Handshake;
+use crate::{state::*, errors::*, constants::*};
+
+/// Cancel a handshake escrow the account funded: CPI into handshake `cancel_transfer`
+/// with the account PDA as sender, refunding the full amount to its token account.
+///
+/// The refund is booked as principal, so a `yield_only` operator cannot spend it
+/// again. The escrow's rent goes back to its recorded `rent_to`.
+///
+/// Remaining accounts: any co-owner signatures.
+pub fn cancel_escrow_from_account<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CancelEscrowFromAccount<'info>>,
+) -> Result<()> {
+    let account = &ctx.accounts.silk_account;
+    let signer_key = ctx.accounts.signer.key();
+
+    let is_owner = account.authorize_action(
+        &signer_key,
+        OPERATOR_CREATE_ESCROW,
+        ctx.remaining_accounts,
+    )?;
+
+    let creator_key = account.creator;
+    let mint_key = account.mint;
+    let account_seeds = &[
+        ACCOUNT_SEED,
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        &[account.bump],
+    ];
+    let signer_seeds = &[&account_seeds[..]];
+
+    let balance_before = ctx.accounts.account_token_account.amount;
+
+    handshake::cpi::cancel_transfer(CpiContext::new_with_signer(
+        ctx.accounts.handshake_program.to_account_info(),
+        handshake::cpi::accounts::CancelTransfer {
+            sender: account.to_account_info(),
+            pool: ctx.accounts.pool.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            pool_token_account: ctx.accounts.pool_token_account.to_account_info(),
+            refund_to: ctx.accounts.account_token_account.to_account_info(),
+            transfer: ctx.accounts.transfer.to_account_info(),
+            rent_to: ctx.accounts.rent_to.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            pool_mint: ctx.accounts.pool_mint.as_ref().map(|pool_mint| pool_mint.to_account_info()),
+        },
+        signer_seeds,
+    ))?;
+
+    // Book what actually arrived, as `deposit` does
+    ctx.accounts.account_token_account.reload()?;
+    let refunded = ctx
+        .accounts
+        .account_token_account
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    let account = &mut ctx.accounts.silk_account;
+    account.principal_balance = account
+        .principal_balance
+        .checked_add(refunded)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    emit!(EscrowCancelledFromAccount {
+        account: account.key(),
+        signer: signer_key,
+        pool: ctx.accounts.pool.key(),
+        transfer: ctx.accounts.transfer.key(),
+        mint: ctx.accounts.mint.key(),
+        amount: refunded,
+        is_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelEscrowFromAccount<'info> {
+    /// Owner or operator with `OPERATOR_CREATE_ESCROW`
+    pub signer: Signer<'info>,
+
+    /// The Silk account that funded the escrow (the escrow's sender)
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// Mint must match the account's mint
+    #[account(
+        constraint = mint.key() == silk_account.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Account PDA's token account (refund destination)
+    #[account(
+        mut,
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Handshake pool — validated by handshake
+    #[account(mut)]
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: Handshake pool's token account — validated by handshake
+    #[account(mut)]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow to cancel — validated by handshake, which checks the sender
+    #[account(mut)]
+    pub transfer: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow's rent recipient — validated by handshake
+    #[account(mut)]
+    pub rent_to: UncheckedAccount<'info>,
+
+    pub handshake_program: Program<'info, Handshake>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Handshake per-mint child, for a pool's added mint — validated by handshake
+    #[account(mut)]
+    pub pool_mint: Option<UncheckedAccount<'info>>,
+}
+
+#[event]
+pub struct EscrowCancelledFromAccount {
+    pub account: Pubkey,
+    pub signer: Pubkey,
+    pub pool: Pubkey,
+    pub transfer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub is_owner: bool,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Owner vetoes an in-flight recovery during the timelock.
+pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+
+    let new_owner = account.recovery_owner.ok_or(SilkysigError::NoRecoveryPending)?;
+
+    account.recovery_owner = None;
+    account.recovery_eta = 0;
+
+    emit!(RecoveryCancelled {
+        account: account.key(),
+        owner: account.owner,
+        new_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    /// Owner or co-owner — further co-owner signatures go in remaining accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct RecoveryCancelled {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*};
+
+/// Stop a recurring payment. Owner approval is required while payments remain;
+/// once the schedule has ended, or was left behind by an earlier account at this
+/// address, anyone may clean it up. Rent returns to its creator.
+pub fn cancel_recurring_payment<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CancelRecurringPayment<'info>>,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    let payment = &ctx.accounts.recurring_payment;
+
+    let finished = payment.is_finished();
+    let stale = payment.account_generation != account.generation;
+    if !finished && !stale {
+        account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+    }
+    if !stale {
+        // Saturating: schedules created before the count existed were never counted
+        account.active_recurring_payments = account.active_recurring_payments.saturating_sub(1);
+    }
+
+    emit!(RecurringPaymentCancelled {
+        account: account.key(),
+        recurring_payment: payment.key(),
+        id: payment.id,
+        signer: ctx.accounts.signer.key(),
+        payments_made: payment.payments_made,
+        finished,
+        stale,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelRecurringPayment<'info> {
+    /// Owner or co-owner (anyone once the schedule has ended or is stale)
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// The schedule being cancelled (closed to its creator)
+    #[account(
+        mut,
+        seeds = [
+            RECURRING_PAYMENT_SEED,
+            silk_account.key().as_ref(),
+            recurring_payment.id.to_le_bytes().as_ref(),
+        ],
+        bump = recurring_payment.bump,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub recurring_payment: Box<Account<'info, RecurringPayment>>,
+
+    /// CHECK: Schedule creator — receives the rent. Validated by has_one.
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+}
+
+#[event]
+pub struct RecurringPaymentCancelled {
+    pub account: Pubkey,
+    pub recurring_payment: Pubkey,
+    pub id: u64,
+    pub signer: Pubkey,
+    pub payments_made: u64,
+    pub finished: bool,
+    /// Left behind by an earlier account at this address
+    pub stale: bool,
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{debug_msg, drift, constants::*, errors::*, state::*};
+
+/// Delete an orphaned Drift user once Drift's idle period has elapsed (permissionless).
+///
+/// Drift refunds the user account's rent to its authority — the Silk account PDA —
+/// which is forwarded to the owner. Works whether or not the Silk account still exists:
+/// - Closed account: rent goes to the owner `close_account` recorded in `drift_cleanup`,
+///   which is closed to them as well.
+/// - Live account: the Drift user must already be detached; rent goes to `silk_account.owner`
+///   and the account stops tracking it.
+/// - Closed before per-mint seeds (no record): rent goes to `creator`.
+///
+/// `mint = None` derives the pre-per-mint `[ACCOUNT_SEED, creator]` PDA so Drift users
+/// orphaned by accounts created before mints were part of the seeds can be reclaimed.
+pub fn cleanup_drift_user(
+    ctx: Context<CleanupDriftUser>,
+    creator: Pubkey,
+    mint: Option<Pubkey>,
+) -> Result<()> {
+    let (expected_pda, bump) = match mint {
+        Some(mint) => Pubkey::find_program_address(
+            &[ACCOUNT_SEED, creator.as_ref(), mint.as_ref()],
+            ctx.program_id,
+        ),
+        None => Pubkey::find_program_address(&[ACCOUNT_SEED, creator.as_ref()], ctx.program_id),
+    };
+    require!(
+        ctx.accounts.silk_account.key() == expected_pda,
+        SilkysigError::InvalidSilkAccount
+    );
+
+    let silk_account_info = ctx.accounts.silk_account.to_account_info();
+    let is_live = silk_account_info.owner == ctx.program_id && !silk_account_info.data_is_empty();
+
+    // Resolve the refund recipient
+    let live_owner = if is_live {
+        let account = SilkAccount::try_deserialize(&mut &silk_account_info.try_borrow_data()?[..])?;
+        require!(
+            account.drift_user != Some(ctx.accounts.drift_user.key()),
+            SilkysigError::DriftUserStillActive
+        );
+        Some(account.owner)
+    } else {
+        None
+    };
+    let rent_recipient = match (&ctx.accounts.drift_cleanup, live_owner) {
+        (Some(record), _) => {
+            require!(
+                record.drift_user == ctx.accounts.drift_user.key(),
+                SilkysigError::InvalidDriftUser
+            );
+            record.owner
+        }
+        (None, Some(owner)) => owner,
+        (None, None) => {
+            // Accounts with per-mint seeds always leave a record when they close
+            require!(mint.is_none(), SilkysigError::MissingDriftCleanup);
+            creator
+        }
+    };
+    require!(
+        ctx.accounts.owner.key() == rent_recipient,
+        SilkysigError::Unauthorized
+    );
+
+    let mint_key = mint.unwrap_or_default();
+    let bump_seed = [bump];
+    let account_seeds: &[&[u8]] = match mint {
+        Some(_) => &[ACCOUNT_SEED, creator.as_ref(), mint_key.as_ref(), &bump_seed],
+        None => &[ACCOUNT_SEED, creator.as_ref(), &bump_seed],
+    };
+    let signer_seeds = &[account_seeds];
+
+    let lamports_before = silk_account_info.lamports();
+
+    let delete_user_accounts = drift_cpi::cpi::accounts::DeleteUser {
+        user: ctx.accounts.drift_user.to_account_info(),
+        user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+        state: ctx.accounts.drift_state.to_account_info(),
+        authority: silk_account_info.clone(),
+    };
+    match drift_cpi::cpi::delete_user(CpiContext::new_with_signer(
+        ctx.accounts.drift_program.to_account_info(),
+        delete_user_accounts,
+        signer_seeds,
+    )) {
+        Ok(_) => {}
+        Err(_e) => {
+            debug_msg!("Drift delete_user failed: {:?}", _e);
+            return Err(SilkysigError::DriftDeleteUserFailed.into());
+        }
+    }
+
+    // Forward the refunded rent from the PDA to the owner
+    let rent_refunded = silk_account_info
+        .lamports()
+        .checked_sub(lamports_before)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    if rent_refunded > 0 {
+        if is_live {
+            // Program-owned: move lamports directly, leaving the account's own rent intact
+            silk_account_info.sub_lamports(rent_refunded)?;
+            ctx.accounts.owner.add_lamports(rent_refunded)?;
+        } else {
+            // Closed PDA is a plain system account — sign for it to transfer out
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: silk_account_info.clone(),
+                        to: ctx.accounts.owner.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                rent_refunded,
+            )?;
+        }
+    }
+
+    // A live account stops tracking the Drift user it detached
+    if is_live {
+        let mut account = SilkAccount::try_deserialize(&mut &silk_account_info.try_borrow_data()?[..])?;
+        if account.detached_drift_user == Some(ctx.accounts.drift_user.key()) {
+            account.detached_drift_user = None;
+            account.try_serialize(&mut &mut silk_account_info.try_borrow_mut_data()?[..])?;
+        }
+    }
+
+    emit!(DriftUserCleanedUp {
+        account: expected_pda,
+        owner: rent_recipient,
+        drift_user: ctx.accounts.drift_user.key(),
+        rent_refunded,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CleanupDriftUser<'info> {
+    /// Anyone can call this (permissionless)
+    pub caller: Signer<'info>,
+
+    /// CHECK: Rent recipient — validated against the recorded, live, or legacy owner
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: Silk account PDA (may already be closed) — validated by seed derivation
+    #[account(mut)]
+    pub silk_account: AccountInfo<'info>,
+
+    /// CHECK: Drift program validates this account (authority must be the Silk account PDA)
+    #[account(mut)]
+    pub drift_user: AccountInfo<'info>,
+
+    /// CHECK: Verified by PDA derivation
+    #[account(
+        mut,
+        address = drift::user_stats_address(&silk_account.key()) @ SilkysigError::InvalidDriftUserStats
+    )]
+    pub drift_user_stats: AccountInfo<'info>,
+
+    /// CHECK: Verified by PDA derivation
+    #[account(
+        mut,
+        address = drift::STATE @ SilkysigError::InvalidDriftState
+    )]
+    pub drift_state: AccountInfo<'info>,
+
+    /// CHECK: Verified by address constraint
+    #[account(address = drift_cpi::ID)]
+    pub drift_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Owner recorded by `close_account` — required once a per-mint account has closed
+    #[account(
+        mut,
+        seeds = [DRIFT_CLEANUP_SEED, silk_account.key().as_ref()],
+        bump = drift_cleanup.bump,
+        close = owner,
+    )]
+    pub drift_cleanup: Option<Box<Account<'info, DriftCleanup>>>,
+}
+
+#[event]
+pub struct DriftUserCleanedUp {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub drift_user: Pubkey,
+    pub rent_refunded: u64,
+}
@@ -2,109 +2,67 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{
-        transfer_checked, close_account as spl_close_account,
+        close_account as spl_close_account,
         TransferChecked, CloseAccount as SplCloseAccount,
         Mint, TokenAccount, TokenInterface,
     },
 };
-use crate::{debug_msg, state::*, errors::*, constants::*};
+use handshake::token_extensions;
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
 
 pub fn close_account<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, CloseAccount<'info>>,
 ) -> Result<()> {
     let account = &ctx.accounts.silk_account;
-    let owner_key = account.owner;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+    // Open schedules would lose their rent and could be run against a re-created account
+    require!(
+        account.active_recurring_payments == 0,
+        SilkysigError::RecurringPaymentsOutstanding
+    );
+
+    let account_owner = account.owner;
+    let creator_key = account.creator;
+    let mint_key = account.mint;
     let account_seeds = &[
         ACCOUNT_SEED,
-        owner_key.as_ref(),
+        creator_key.as_ref(),
+        mint_key.as_ref(),
         &[account.bump],
     ];
     let signer_seeds = &[&account_seeds[..]];
 
-    // If Drift is initialized, withdraw everything and clean up Drift accounts
-    if account.drift_user.is_some() {
-        let remaining = &ctx.remaining_accounts;
-        require!(remaining.len() >= 8, SilkysigError::MissingDriftAccounts);
-
-        let drift_state = &remaining[0];
-        let drift_user = &remaining[1];
-        let drift_user_stats = &remaining[2];
-        let drift_spot_market_vault = &remaining[3];
-        let drift_signer = &remaining[4];
-        let drift_program = &remaining[5];
-        let drift_oracle = &remaining[6];
-        let drift_spot_market = &remaining[7];
-
-        // Validate drift program address
-        require!(
-            drift_program.key() == drift_cpi::ID,
-            SilkysigError::InvalidDriftProgram
-        );
-
-        // Validate drift user matches account's stored key
-        require!(
-            drift_user.key() == account.drift_user.unwrap(),
-            SilkysigError::InvalidDriftUser
-        );
-
-        let market_index = account.drift_market_index.unwrap();
-
-        // Withdraw all funds from Drift. u64::MAX + reduce_only=true tells Drift
-        // to withdraw the full balance without risking InsufficientCollateral errors.
-        let withdraw_accounts = drift_cpi::cpi::accounts::Withdraw {
-            state: drift_state.to_account_info(),
-            user: drift_user.to_account_info(),
-            user_stats: drift_user_stats.to_account_info(),
-            authority: account.to_account_info(),
-            spot_market_vault: drift_spot_market_vault.to_account_info(),
-            drift_signer: drift_signer.to_account_info(),
-            user_token_account: ctx.accounts.account_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        };
-
-        let drift_remaining = vec![
-            drift_oracle.to_account_info(),
-            drift_spot_market.to_account_info(),
-        ];
+    // If a yield adapter is active, withdraw the whole position first
+    if let Some(adapter) = yield_adapter::load(AdapterContext {
+        silk_account: account,
+        remaining_accounts: ctx.remaining_accounts,
+        account_token_account: ctx.accounts.account_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })? {
+        adapter.withdraw_all()?;
 
-        let withdraw_ctx = CpiContext::new_with_signer(
-            drift_program.to_account_info(),
-            withdraw_accounts,
-            signer_seeds,
-        )
-        .with_remaining_accounts(drift_remaining);
-
-        match drift_cpi::cpi::withdraw(withdraw_ctx, market_index, u64::MAX, true) {
-            Ok(_) => {}
-            Err(_e) => {
-                debug_msg!("Drift withdraw failed: {:?}", _e);
-                return Err(SilkysigError::DriftWithdrawFailed.into());
-            }
-        }
-
-        // Refresh token account balance after Drift withdrawal
+        // Refresh token account balance after the withdrawal
         ctx.accounts.account_token_account.reload()?;
+    }
 
-        // Drift requires the user account to be "idle" for ~13 days
-        // (~1,123,200 slots) before delete_user is allowed (error 6152:
-        // UserCantBeDeleted). After the withdrawal above the Drift user has
-        // zero balance but cannot be deleted yet. A dedicated
-        // `cleanup_drift_user` instruction can be added later to reclaim the
-        // rent once the idle period has elapsed.
-        //
-        // let delete_user_accounts = drift_cpi::cpi::accounts::DeleteUser {
-        //     user: drift_user.to_account_info(),
-        //     user_stats: drift_user_stats.to_account_info(),
-        //     state: drift_state.to_account_info(),
-        //     authority: account.to_account_info(),
-        // };
-        // drift_cpi::cpi::delete_user(
-        //     CpiContext::new_with_signer(
-        //         drift_program.to_account_info(),
-        //         delete_user_accounts,
-        //         signer_seeds,
-        //     ),
-        // )?;
+    // Drift requires the user account to be "idle" for ~13 days
+    // (~1,123,200 slots) before delete_user is allowed (error 6152:
+    // UserCantBeDeleted). An attached user was just emptied above and a detached
+    // one was emptied by `disable_drift`; either way it cannot be deleted yet.
+    // Once the idle period has elapsed, `cleanup_drift_user` deletes it and
+    // refunds the rent to the owner recorded here, even though this account is
+    // closed by then.
+    if let Some(drift_user) = account.drift_user.or(account.detached_drift_user) {
+        let record = ctx
+            .accounts
+            .drift_cleanup
+            .as_mut()
+            .ok_or(SilkysigError::MissingDriftCleanup)?;
+        record.version = 1;
+        record.bump = ctx.bumps.drift_cleanup.ok_or(SilkysigError::MissingDriftCleanup)?;
+        record.account = ctx.accounts.silk_account.key();
+        record.owner = account_owner;
+        record.drift_user = drift_user;
     }
 
     let swept_amount = ctx.accounts.account_token_account.amount;
@@ -122,7 +80,12 @@ pub fn close_account<'a, 'b, 'c, 'info>(
             transfer_accounts,
             signer_seeds,
         );
-        transfer_checked(cpi_ctx, swept_amount, ctx.accounts.mint.decimals)?;
+        token_extensions::transfer_checked(
+            cpi_ctx,
+            ctx.remaining_accounts,
+            swept_amount,
+            ctx.accounts.mint.decimals,
+        )?;
     }
 
     // 2. Close the PDA's ATA (rent lamports -> owner)
@@ -142,7 +105,7 @@ pub fn close_account<'a, 'b, 'c, 'info>(
 
     emit!(AccountClosed {
         account: ctx.accounts.silk_account.key(),
-        owner: owner_key,
+        owner: account_owner,
         swept_amount,
     });
 
@@ -151,12 +114,17 @@ pub fn close_account<'a, 'b, 'c, 'info>(
 
 #[derive(Accounts)]
 pub struct CloseAccount<'info> {
+    /// Owner or co-owner — pays for owner ATA init if needed
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Account owner — receives swept tokens and rent. Validated by has_one.
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [ACCOUNT_SEED, owner.key().as_ref()],
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
         bump = silk_account.bump,
         has_one = owner,
         close = owner,
@@ -178,7 +146,7 @@ pub struct CloseAccount<'info> {
 
     #[account(
         init_if_needed,
-        payer = owner,
+        payer = signer,
         associated_token::mint = mint,
         associated_token::authority = owner,
         associated_token::token_program = token_program,
@@ -188,6 +156,16 @@ pub struct CloseAccount<'info> {
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    /// Records the closing owner for `cleanup_drift_user` — required while a Drift user is attached or detached
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = DriftCleanup::SPACE,
+        seeds = [DRIFT_CLEANUP_SEED, silk_account.key().as_ref()],
+        bump,
+    )]
+    pub drift_cleanup: Option<Box<Account<'info, DriftCleanup>>>,
 }
 
 #[event]
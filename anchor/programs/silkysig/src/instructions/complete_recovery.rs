@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Finalize a recovery once its timelock has elapsed (permissionless)
+pub fn complete_recovery(ctx: Context<CompleteRecovery>) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+
+    let new_owner = account.recovery_owner.ok_or(SilkysigError::NoRecoveryPending)?;
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= account.recovery_eta,
+        SilkysigError::RecoveryTimelockActive
+    );
+
+    let previous_owner = account.owner;
+    account.set_owner(new_owner);
+
+    emit!(RecoveryCompleted {
+        account: account.key(),
+        previous_owner,
+        new_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteRecovery<'info> {
+    /// Anyone can call this (permissionless)
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct RecoveryCompleted {
+    pub account: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
@@ -3,16 +3,25 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
-use crate::{state::*, constants::*};
+use handshake::token_extensions;
+use crate::{state::*, errors::*, constants::*};
 
 pub fn create_account(
     ctx: Context<CreateAccount>,
 ) -> Result<()> {
+    require!(
+        token_extensions::has_supported_extensions(&ctx.accounts.mint.to_account_info())?,
+        SilkysigError::UnsupportedMintExtension
+    );
+
     let account = &mut ctx.accounts.silk_account;
+    let slot = Clock::get()?.slot;
 
-    account.version = 1;
+    // v2 layout — see SilkAccount; v1 accounts are not migrated
+    account.version = 2;
     account.bump = ctx.bumps.silk_account;
     account.owner = ctx.accounts.owner.key();
+    account.creator = ctx.accounts.owner.key();
     account.mint = ctx.accounts.mint.key();
     account.is_paused = false;
     account.operator_count = 0;
@@ -20,7 +29,33 @@ pub fn create_account(
     account.drift_user = None;
     account.drift_market_index = None;
     account.principal_balance = 0;
-    account._reserved = [0u8; 64];
+    account.co_owners = [Pubkey::default(); MAX_CO_OWNERS];
+    account.co_owner_count = 0;
+    account.owner_threshold = 0;
+    account.pending_owner = None;
+    account.guardians = [Pubkey::default(); MAX_GUARDIANS];
+    account.guardian_count = 0;
+    account.guardian_threshold = 0;
+    account.recovery_delay = 0;
+    account.recovery_owner = None;
+    account.recovery_eta = 0;
+    account.next_pending_transfer_id = 0;
+    account.pause_guardian = None;
+    account.last_synced_value = 0;
+    account.cumulative_yield = 0;
+    account.last_yield_sync_slot = 0;
+    account.yield_withdrawn = 0;
+    account.yield_adapter = YieldAdapterKind::None;
+    account.buffer_bps = 0;
+    account.buffer_amount = 0;
+    // Schedule ids start at the creation slot so they never land on a
+    // RecurringPayment PDA an earlier account at this address left behind
+    account.next_recurring_payment_id = slot;
+    account.drift_bumps = DriftBumps::default();
+    account.detached_drift_user = None;
+    account.generation = slot;
+    account.active_recurring_payments = 0;
+    account._reserved = [0u8; 16];
 
     emit!(AccountCreated {
         account: account.key(),
@@ -39,12 +74,12 @@ pub struct CreateAccount<'info> {
     /// The token mint this account will hold
     pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Account PDA — one per owner
+    /// Account PDA — one per (owner, mint)
     #[account(
         init,
         payer = owner,
         space = SilkAccount::SPACE,
-        seeds = [ACCOUNT_SEED, owner.key().as_ref()],
+        seeds = [ACCOUNT_SEED, owner.key().as_ref(), mint.key().as_ref()],
         bump
     )]
     pub silk_account: Box<Account<'info, SilkAccount>>,
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use handshake::{program::Handshake, state::SecureTransfer};
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
+
+/// Fund a handshake escrow from the account: CPI into handshake `create_transfer`
+/// with the account PDA as sender, so refunds come back to its token account.
+/// `cancel_escrow_from_account` cancels it again.
+///
+/// The signer pre-funds the escrow's rent — a PDA carrying data cannot pay for
+/// account creation itself, and handshake only tops up an underfunded address —
+/// and is recorded as the escrow's `rent_to`.
+///
+/// Remaining accounts: the adapter's venue accounts (if any), then any co-owner signatures.
+pub fn create_escrow_from_account<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CreateEscrowFromAccount<'info>>,
+    recipient: Pubkey,
+    nonce: u64,
+    amount: u64,
+    memo: String,
+    claimable_after: i64,
+    claimable_until: i64,
+) -> Result<()> {
+    require!(amount > 0, SilkysigError::AmountMustBePositive);
+
+    let account = &mut ctx.accounts.silk_account;
+    let signer_key = ctx.accounts.signer.key();
+
+    let adapter = yield_adapter::load(AdapterContext {
+        silk_account: account,
+        remaining_accounts: ctx.remaining_accounts,
+        account_token_account: ctx.accounts.account_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })?;
+
+    // Value before the withdrawal decides how much of it is yield vs principal
+    let current_value = yield_adapter::account_value(
+        adapter.as_deref(),
+        ctx.accounts.account_token_account.amount,
+    )?;
+
+    let is_owner = account.authorize_spend(
+        &signer_key,
+        OPERATOR_CREATE_ESCROW,
+        amount,
+        current_value,
+        ctx.remaining_accounts,
+    )?;
+
+    // Pay from the liquidity buffer; only the shortfall comes out of the venue
+    let shortfall = amount.saturating_sub(ctx.accounts.account_token_account.amount);
+    if let Some(adapter) = &adapter {
+        if shortfall > 0 {
+            adapter.withdraw(shortfall)?;
+        }
+    }
+    // Release the adapter's borrow of the account before updating it
+    drop(adapter);
+
+    let rent = Rent::get()?
+        .minimum_balance(SecureTransfer::SPACE)
+        .saturating_sub(ctx.accounts.transfer.lamports());
+    if rent > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.transfer.to_account_info(),
+                },
+            ),
+            rent,
+        )?;
+    }
+
+    let creator_key = account.creator;
+    let mint_key = account.mint;
+    let account_seeds = &[
+        ACCOUNT_SEED,
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        &[account.bump],
+    ];
+    let signer_seeds = &[&account_seeds[..]];
+
+    handshake::cpi::create_transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.handshake_program.to_account_info(),
+            handshake::cpi::accounts::CreateTransfer {
+                sender: account.to_account_info(),
+                pool: ctx.accounts.pool.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                pool_token_account: ctx.accounts.pool_token_account.to_account_info(),
+                sender_token_account: ctx.accounts.account_token_account.to_account_info(),
+                transfer: ctx.accounts.transfer.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                refund_to: None,
+                rent_to: Some(ctx.accounts.signer.to_account_info()),
+                pool_mint: ctx.accounts.pool_mint.as_ref().map(|pool_mint| pool_mint.to_account_info()),
+            },
+            signer_seeds,
+        ),
+        recipient,
+        nonce,
+        amount,
+        memo,
+        claimable_after,
+        claimable_until,
+    )?;
+
+    let (from_yield, from_principal) = account.record_withdrawal(amount, current_value)?;
+
+    emit!(EscrowCreatedFromAccount {
+        account: ctx.accounts.silk_account.key(),
+        signer: signer_key,
+        pool: ctx.accounts.pool.key(),
+        transfer: ctx.accounts.transfer.key(),
+        recipient,
+        mint: ctx.accounts.mint.key(),
+        amount,
+        from_yield,
+        from_principal,
+        is_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateEscrowFromAccount<'info> {
+    /// Owner or operator with `OPERATOR_CREATE_ESCROW` — pays the escrow's rent
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The Silk account funding the escrow (the escrow's sender)
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// Mint must match the account's mint
+    #[account(
+        constraint = mint.key() == silk_account.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Account PDA's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Handshake pool — validated by handshake
+    #[account(mut)]
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: Handshake pool's token account — validated by handshake
+    #[account(mut)]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA to create — seeds validated by handshake
+    #[account(mut)]
+    pub transfer: UncheckedAccount<'info>,
+
+    pub handshake_program: Program<'info, Handshake>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Handshake per-mint child, for a pool's added mint — validated by handshake
+    #[account(mut)]
+    pub pool_mint: Option<UncheckedAccount<'info>>,
+}
+
+#[event]
+pub struct EscrowCreatedFromAccount {
+    pub account: Pubkey,
+    pub signer: Pubkey,
+    pub pool: Pubkey,
+    pub transfer: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub from_yield: u64,
+    pub from_principal: u64,
+    pub is_owner: bool,
+}
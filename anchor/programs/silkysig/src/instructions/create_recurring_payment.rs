@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Schedule `amount` to `recipient` every `interval` seconds from `first_due`,
+/// optionally until `end_at`. Payments are made by the `execute_recurring` crank;
+/// `max_catch_up` caps how many missed periods it pays back once it runs again.
+pub fn create_recurring_payment(
+    ctx: Context<CreateRecurringPayment>,
+    amount: u64,
+    interval: i64,
+    first_due: i64,
+    end_at: Option<i64>,
+    max_catch_up: Option<u16>,
+) -> Result<()> {
+    require!(amount > 0, SilkysigError::AmountMustBePositive);
+
+    let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    require!(
+        interval >= MIN_RECURRING_INTERVAL
+            && first_due >= clock.unix_timestamp
+            && !matches!(end_at, Some(end_at) if end_at < first_due)
+            && max_catch_up != Some(0),
+        SilkysigError::InvalidRecurringSchedule
+    );
+
+    let id = account.next_recurring_payment_id;
+    account.next_recurring_payment_id = id
+        .checked_add(1)
+        .ok_or(SilkysigError::MathOverflow)?;
+    account.active_recurring_payments = account
+        .active_recurring_payments
+        .checked_add(1)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    let payment = &mut ctx.accounts.recurring_payment;
+    payment.version = 1;
+    payment.bump = ctx.bumps.recurring_payment;
+    payment.account = account.key();
+    payment.id = id;
+    payment.rent_payer = ctx.accounts.signer.key();
+    payment.recipient = ctx.accounts.recipient.key();
+    payment.amount = amount;
+    payment.interval = interval;
+    payment.next_due = first_due;
+    payment.end_at = end_at;
+    payment.payments_made = 0;
+    payment.max_catch_up = max_catch_up;
+    payment.account_generation = account.generation;
+    payment._reserved = [0u8; 21];
+
+    emit!(RecurringPaymentCreated {
+        account: account.key(),
+        recurring_payment: payment.key(),
+        id,
+        recipient: payment.recipient,
+        amount,
+        interval,
+        first_due,
+        end_at,
+        max_catch_up,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateRecurringPayment<'info> {
+    /// Owner or co-owner — pays rent for the schedule (refunded on cancel)
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = RecurringPayment::SPACE,
+        seeds = [
+            RECURRING_PAYMENT_SEED,
+            silk_account.key().as_ref(),
+            silk_account.next_recurring_payment_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub recurring_payment: Box<Account<'info, RecurringPayment>>,
+
+    /// CHECK: Recipient address — only stored for the later ATA derivation
+    pub recipient: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RecurringPaymentCreated {
+    pub account: Pubkey,
+    pub recurring_payment: Pubkey,
+    pub id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub interval: i64,
+    pub first_due: i64,
+    pub end_at: Option<i64>,
+    pub max_catch_up: Option<u16>,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*};
+
+/// Reject a queued operator transfer. Owner approval is required until the
+/// request expires; after that anyone may clean it up. Rent returns to the operator.
+pub fn deny_pending_transfer<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DenyPendingTransfer<'info>>,
+) -> Result<()> {
+    let account = &ctx.accounts.silk_account;
+    let pending = &ctx.accounts.pending_transfer;
+
+    let clock = Clock::get()?;
+    let expired = pending.is_expired(clock.unix_timestamp);
+    if !expired {
+        account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+    }
+
+    emit!(PendingTransferDenied {
+        account: account.key(),
+        pending_transfer: pending.key(),
+        id: pending.id,
+        signer: ctx.accounts.signer.key(),
+        operator: pending.requester,
+        amount: pending.amount,
+        expired,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DenyPendingTransfer<'info> {
+    /// Owner or co-owner (anyone once the request has expired)
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// The request being denied (closed to the requesting operator)
+    #[account(
+        mut,
+        seeds = [
+            PENDING_TRANSFER_SEED,
+            silk_account.key().as_ref(),
+            pending_transfer.id.to_le_bytes().as_ref(),
+        ],
+        bump = pending_transfer.bump,
+        has_one = requester,
+        close = requester,
+    )]
+    pub pending_transfer: Box<Account<'info, PendingTransfer>>,
+
+    /// CHECK: Requesting operator — receives the request's rent. Validated by has_one.
+    #[account(mut)]
+    pub requester: AccountInfo<'info>,
+}
+
+#[event]
+pub struct PendingTransferDenied {
+    pub account: Pubkey,
+    pub pending_transfer: Pubkey,
+    pub id: u64,
+    pub signer: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub expired: bool,
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
-    transfer_checked, TransferChecked, Mint, TokenAccount, TokenInterface,
+    TransferChecked, Mint, TokenAccount, TokenInterface,
 };
-use crate::{debug_msg, state::*, errors::*, constants::*};
+use handshake::token_extensions;
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
 
 pub fn deposit<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, Deposit<'info>>,
@@ -10,7 +11,9 @@ pub fn deposit<'a, 'b, 'c, 'info>(
 ) -> Result<()> {
     require!(amount > 0, SilkysigError::AmountMustBePositive);
 
-    // SPL transfer from depositor to account ATA
+    // SPL transfer from depositor to account ATA. Principal is booked from the
+    // balance delta so Token-2022 transfer fees never inflate it.
+    let balance_before = ctx.accounts.account_token_account.amount;
     let transfer_accounts = TransferChecked {
         from: ctx.accounts.depositor_token_account.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
@@ -21,86 +24,55 @@ pub fn deposit<'a, 'b, 'c, 'info>(
         ctx.accounts.token_program.to_account_info(),
         transfer_accounts,
     );
-    transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.account_token_account.reload()?;
+    let received = ctx
+        .accounts
+        .account_token_account
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(SilkysigError::MathOverflow)?;
 
-    // If Drift is initialized, forward tokens into Drift
     let account = &mut ctx.accounts.silk_account;
-    if account.drift_user.is_some() {
-        let remaining = &ctx.remaining_accounts;
-        require!(remaining.len() >= 7, SilkysigError::MissingDriftAccounts);
-
-        let drift_state = &remaining[0];
-        let drift_user = &remaining[1];
-        let drift_user_stats = &remaining[2];
-        let drift_spot_market_vault = &remaining[3];
-        let drift_program = &remaining[4];
-        let drift_oracle = &remaining[5];
-        let drift_spot_market = &remaining[6];
-
-        // Validate drift program address
-        require!(
-            drift_program.key() == drift_cpi::ID,
-            SilkysigError::InvalidDriftProgram
-        );
-
-        // Validate drift user matches account's stored key
-        require!(
-            drift_user.key() == account.drift_user.unwrap(),
-            SilkysigError::InvalidDriftUser
-        );
-
-        let market_index = account.drift_market_index.unwrap();
-
-        let owner_key = account.owner;
-        let account_seeds = &[
-            ACCOUNT_SEED,
-            owner_key.as_ref(),
-            &[account.bump],
-        ];
-        let signer_seeds = &[&account_seeds[..]];
-
-        let deposit_accounts = drift_cpi::cpi::accounts::Deposit {
-            state: drift_state.to_account_info(),
-            user: drift_user.to_account_info(),
-            user_stats: drift_user_stats.to_account_info(),
-            authority: account.to_account_info(),
-            spot_market_vault: drift_spot_market_vault.to_account_info(),
-            user_token_account: ctx.accounts.account_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        };
-
-        let drift_remaining = vec![
-            drift_oracle.to_account_info(),
-            drift_spot_market.to_account_info(),
-        ];
-
-        let deposit_ctx = CpiContext::new_with_signer(
-            drift_program.to_account_info(),
-            deposit_accounts,
-            signer_seeds,
-        )
-        .with_remaining_accounts(drift_remaining);
+    let principal_balance = account
+        .principal_balance
+        .checked_add(received)
+        .ok_or(SilkysigError::MathOverflow)?;
 
-        match drift_cpi::cpi::deposit(deposit_ctx, market_index, amount, false) {
-            Ok(_) => {}
-            Err(_e) => {
-                debug_msg!("Drift deposit failed: {:?}", _e);
-                return Err(SilkysigError::DriftDepositFailed.into());
-            }
+    // If a yield adapter is active, top up the liquidity buffer first and forward
+    // the rest into its venue. Principal stands in for total value here;
+    // `rebalance` settles the buffer against the live value.
+    if let Some(adapter) = yield_adapter::load(AdapterContext {
+        silk_account: account,
+        remaining_accounts: ctx.remaining_accounts,
+        account_token_account: ctx.accounts.account_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })? {
+        let buffer_target = account.buffer_target(principal_balance);
+        let to_venue = ctx
+            .accounts
+            .account_token_account
+            .amount
+            .saturating_sub(buffer_target)
+            .min(received);
+        if to_venue > 0 {
+            adapter.deposit(to_venue)?;
         }
     }
 
-    // Update principal balance
-    account.principal_balance = account
-        .principal_balance
-        .checked_add(amount)
-        .ok_or(SilkysigError::MathOverflow)?;
+    account.principal_balance = principal_balance;
 
     emit!(Deposited {
         account: ctx.accounts.silk_account.key(),
         depositor: ctx.accounts.depositor.key(),
         mint: ctx.accounts.mint.key(),
-        amount,
+        amount: received,
     });
 
     Ok(())
@@ -114,7 +86,7 @@ pub struct Deposit<'info> {
     /// The Silk account receiving the deposit
     #[account(
         mut,
-        seeds = [ACCOUNT_SEED, silk_account.owner.as_ref()],
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
         bump = silk_account.bump,
     )]
     pub silk_account: Box<Account<'info, SilkAccount>>,
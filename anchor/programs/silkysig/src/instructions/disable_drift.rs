@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use crate::{drift, state::*, errors::*, constants::*};
+
+/// Pull the full Drift position back into the account's ATA and detach Drift.
+///
+/// The Drift user is left in place (Drift only lets idle users be deleted) and
+/// remembered as detached, so `close_account` records it for `cleanup_drift_user`.
+pub fn disable_drift<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DisableDrift<'info>>,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.authorize_action(&ctx.accounts.signer.key(), OPERATOR_MANAGE_VENUE, ctx.remaining_accounts)?;
+
+    let drift_user = account.drift_user.ok_or(SilkysigError::DriftNotInitialized)?;
+    let market_index = account
+        .drift_market_index
+        .ok_or(SilkysigError::DriftNotInitialized)?;
+
+    // u64::MAX + reduce_only=true withdraws the full balance without risking
+    // InsufficientCollateral errors
+    let balance_before = ctx.accounts.account_token_account.amount;
+    drift::withdraw(
+        account,
+        ctx.remaining_accounts,
+        ctx.accounts.account_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        u64::MAX,
+        true,
+    )?;
+    ctx.accounts.account_token_account.reload()?;
+    let withdrawn = ctx
+        .accounts
+        .account_token_account
+        .amount
+        .saturating_sub(balance_before);
+
+    account.drift_user = None;
+    account.drift_market_index = None;
+    account.detached_drift_user = Some(drift_user);
+    account.yield_adapter = YieldAdapterKind::None;
+
+    emit!(DriftDisabled {
+        account: account.key(),
+        owner: account.owner,
+        drift_user,
+        market_index,
+        withdrawn,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DisableDrift<'info> {
+    /// Owner, co-owner, or operator with `OPERATOR_MANAGE_VENUE` — further co-owner signatures go in remaining accounts after the Drift accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// Account PDA's token account (receives the withdrawn funds)
+    #[account(
+        mut,
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct DriftDisabled {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub drift_user: Pubkey,
+    pub market_index: u16,
+    pub withdrawn: u64,
+}
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        TransferChecked, Mint, TokenAccount, TokenInterface,
+    },
+};
+use handshake::token_extensions;
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
+
+/// Pay one due period of a recurring payment (permissionless crank).
+///
+/// Each call pays a single period, so a crank that fell behind catches up one
+/// call at a time, back to at most the schedule's `max_catch_up` latest missed
+/// periods; older ones are skipped unpaid. Remaining accounts: the account's venue accounts, if any, then
+/// any transfer-hook accounts for the mint.
+pub fn execute_recurring<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ExecuteRecurring<'info>>,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    let payment = &mut ctx.accounts.recurring_payment;
+
+    require!(!account.is_paused, SilkysigError::AccountPaused);
+    require!(
+        payment.account_generation == account.generation,
+        SilkysigError::StaleRecurringPayment
+    );
+    require!(!payment.is_finished(), SilkysigError::RecurringPaymentEnded);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= payment.next_due,
+        SilkysigError::RecurringPaymentNotDue
+    );
+    let periods_skipped = payment.skip_missed_periods(clock.unix_timestamp)?;
+
+    let amount = payment.amount;
+
+    let adapter = yield_adapter::load(AdapterContext {
+        silk_account: account,
+        remaining_accounts: ctx.remaining_accounts,
+        account_token_account: ctx.accounts.account_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })?;
+
+    // Value before the withdrawal decides how much of it is yield vs principal
+    let current_value = yield_adapter::account_value(
+        adapter.as_deref(),
+        ctx.accounts.account_token_account.amount,
+    )?;
+
+    // Pay from the liquidity buffer; only the shortfall comes out of the venue
+    let shortfall = amount.saturating_sub(ctx.accounts.account_token_account.amount);
+    if let Some(adapter) = &adapter {
+        if shortfall > 0 {
+            adapter.withdraw(shortfall)?;
+        }
+    }
+    // Release the adapter's borrow of the account before updating it
+    drop(adapter);
+
+    let creator_key = account.creator;
+    let mint_key = account.mint;
+    let account_seeds = &[
+        ACCOUNT_SEED,
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        &[account.bump],
+    ];
+    let signer_seeds = &[&account_seeds[..]];
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.account_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_accounts,
+        signer_seeds,
+    );
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let (from_yield, from_principal) = account.record_withdrawal(amount, current_value)?;
+
+    let paid_due = payment.next_due;
+    payment.next_due = paid_due
+        .checked_add(payment.interval)
+        .ok_or(SilkysigError::MathOverflow)?;
+    payment.payments_made = payment
+        .payments_made
+        .checked_add(1)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    emit!(RecurringPaymentExecuted {
+        account: account.key(),
+        recurring_payment: payment.key(),
+        id: payment.id,
+        recipient: payment.recipient,
+        amount,
+        from_yield,
+        from_principal,
+        paid_due,
+        next_due: payment.next_due,
+        payments_made: payment.payments_made,
+        periods_skipped,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteRecurring<'info> {
+    /// Anyone can call this (permissionless) — pays for recipient ATA init if needed
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            RECURRING_PAYMENT_SEED,
+            silk_account.key().as_ref(),
+            recurring_payment.id.to_le_bytes().as_ref(),
+        ],
+        bump = recurring_payment.bump,
+        has_one = recipient,
+    )]
+    pub recurring_payment: Box<Account<'info, RecurringPayment>>,
+
+    /// Mint must match the account's mint
+    #[account(
+        constraint = mint.key() == silk_account.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Account PDA's token account (source)
+    #[account(
+        mut,
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Recipient address — validated by has_one, only used as ATA derivation authority
+    pub recipient: AccountInfo<'info>,
+
+    /// Recipient's token account — initialized if needed, caller pays rent
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RecurringPaymentExecuted {
+    pub account: Pubkey,
+    pub recurring_payment: Pubkey,
+    pub id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub from_yield: u64,
+    pub from_principal: u64,
+    pub paid_due: i64,
+    pub next_due: i64,
+    pub payments_made: u64,
+    /// Missed periods skipped unpaid by this call (see `max_catch_up`)
+    pub periods_skipped: u64,
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use handshake::token_extensions;
 
-use crate::{debug_msg, constants::*, errors::*, state::*};
+use crate::{debug_msg, drift, constants::*, errors::*, state::*};
 
 pub fn init_drift_user(
     ctx: Context<InitDriftUser>,
@@ -10,60 +11,89 @@ pub fn init_drift_user(
     market_index: u16,
 ) -> Result<()> {
     let account = &mut ctx.accounts.silk_account;
+    account.authorize_action(&ctx.accounts.signer.key(), OPERATOR_MANAGE_VENUE, ctx.remaining_accounts)?;
 
-    // Guard: drift user not already initialized
+    // Guard: drift user not already initialized, and no other venue active
     require!(
         account.drift_user.is_none(),
         SilkysigError::DriftUserAlreadyInitialized
     );
+    require!(
+        account.yield_adapter == YieldAdapterKind::None,
+        SilkysigError::YieldAdapterActive
+    );
+    // Drift's deposit and withdraw CPIs do not forward transfer hook accounts
+    require!(
+        token_extensions::transfer_hook_program(&ctx.accounts.mint.to_account_info())?.is_none(),
+        SilkysigError::DriftHookedMint
+    );
+    // Only one detached Drift user is tracked: re-attach it or clean it up first
+    require!(
+        account.detached_drift_user.is_none()
+            || account.detached_drift_user == Some(ctx.accounts.drift_user.key()),
+        SilkysigError::DetachedDriftUserPending
+    );
 
     // Build signer seeds for silk_account PDA
-    let owner_key = account.owner;
+    let creator_key = account.creator;
+    let mint_key = account.mint;
     let account_seeds = &[
         ACCOUNT_SEED,
-        owner_key.as_ref(),
+        creator_key.as_ref(),
+        mint_key.as_ref(),
         &[account.bump],
     ];
     let signer_seeds = &[&account_seeds[..]];
 
+    // Drift accounts left behind by `disable_drift` (or a closed account) are reused:
+    // user stats are per authority, and a detached user keeps its sub account id
+    let is_drift_account = |info: &AccountInfo| info.owner == &drift_cpi::ID && !info.data_is_empty();
+
     // CPI 1: Initialize user stats
-    drift_cpi::cpi::initialize_user_stats(
-        CpiContext::new_with_signer(
-            ctx.accounts.drift_program.to_account_info(),
-            drift_cpi::cpi::accounts::InitializeUserStats {
-                authority: account.to_account_info(),
-                payer: ctx.accounts.owner.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                state: ctx.accounts.drift_state.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-                user_stats: ctx.accounts.drift_user_stats.to_account_info(),
-            },
-            signer_seeds,
-        ),
-    )?;
+    if !is_drift_account(&ctx.accounts.drift_user_stats) {
+        drift_cpi::cpi::initialize_user_stats(
+            CpiContext::new_with_signer(
+                ctx.accounts.drift_program.to_account_info(),
+                drift_cpi::cpi::accounts::InitializeUserStats {
+                    authority: account.to_account_info(),
+                    payer: ctx.accounts.signer.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    state: ctx.accounts.drift_state.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+                },
+                signer_seeds,
+            ),
+        )?;
+    }
 
-    // CPI 2: Initialize user
-    drift_cpi::cpi::initialize_user(
-        CpiContext::new_with_signer(
-            ctx.accounts.drift_program.to_account_info(),
-            drift_cpi::cpi::accounts::InitializeUser {
-                user: ctx.accounts.drift_user.to_account_info(),
-                user_stats: ctx.accounts.drift_user_stats.to_account_info(),
-                authority: account.to_account_info(),
-                payer: ctx.accounts.owner.to_account_info(),
-                state: ctx.accounts.drift_state.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        sub_account_id,
-        name,
-    )?;
+    // CPI 2: Initialize user (`name` only applies to a new user)
+    if !is_drift_account(&ctx.accounts.drift_user) {
+        drift_cpi::cpi::initialize_user(
+            CpiContext::new_with_signer(
+                ctx.accounts.drift_program.to_account_info(),
+                drift_cpi::cpi::accounts::InitializeUser {
+                    user: ctx.accounts.drift_user.to_account_info(),
+                    user_stats: ctx.accounts.drift_user_stats.to_account_info(),
+                    authority: account.to_account_info(),
+                    payer: ctx.accounts.signer.to_account_info(),
+                    state: ctx.accounts.drift_state.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            sub_account_id,
+            name,
+        )?;
+    }
 
     // Bootstrap: if ATA has existing balance, deposit into Drift
     let existing_balance = ctx.accounts.account_token_account.amount;
     if existing_balance > 0 {
+        drift::MarketConditions::load(&ctx.accounts.drift_state, &ctx.accounts.drift_spot_market)?
+            .check(drift::MarketAction::Deposit)?;
+
         let deposit_accounts = drift_cpi::cpi::accounts::Deposit {
             state: ctx.accounts.drift_state.to_account_info(),
             user: ctx.accounts.drift_user.to_account_info(),
@@ -99,7 +129,10 @@ pub fn init_drift_user(
     // principal_balance is NOT modified here — the deposit instruction already
     // tracked the funds. The bootstrap just moves them from ATA to Drift.
     account.drift_user = Some(ctx.accounts.drift_user.key());
+    account.detached_drift_user = None;
     account.drift_market_index = Some(market_index);
+    account.drift_bumps = DriftBumps::find(&account.key(), market_index);
+    account.yield_adapter = YieldAdapterKind::Drift;
 
     emit!(DriftUserInitialized {
         account: account.key(),
@@ -115,14 +148,14 @@ pub fn init_drift_user(
 #[derive(Accounts)]
 #[instruction(sub_account_id: u16, name: [u8; 32], market_index: u16)]
 pub struct InitDriftUser<'info> {
+    /// Owner, co-owner, or operator with `OPERATOR_MANAGE_VENUE` — pays rent for the Drift accounts
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub signer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [ACCOUNT_SEED, owner.key().as_ref()],
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
         bump = silk_account.bump,
-        has_one = owner,
     )]
     pub silk_account: Box<Account<'info, SilkAccount>>,
 
@@ -140,24 +173,39 @@ pub struct InitDriftUser<'info> {
     )]
     pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Drift program validates this account
-    #[account(mut)]
+    /// CHECK: Verified by PDA derivation; initialized by Drift unless a detached user is reused
+    #[account(
+        mut,
+        address = drift::user_address(&silk_account.key(), sub_account_id) @ SilkysigError::InvalidDriftUser
+    )]
     pub drift_user: AccountInfo<'info>,
 
-    /// CHECK: Drift program validates this account
-    #[account(mut)]
+    /// CHECK: Verified by PDA derivation; initialized by Drift on first use
+    #[account(
+        mut,
+        address = drift::user_stats_address(&silk_account.key()) @ SilkysigError::InvalidDriftUserStats
+    )]
     pub drift_user_stats: AccountInfo<'info>,
 
-    /// CHECK: Drift program validates this account
-    #[account(mut)]
+    /// CHECK: Verified by PDA derivation
+    #[account(
+        mut,
+        address = drift::STATE @ SilkysigError::InvalidDriftState
+    )]
     pub drift_state: AccountInfo<'info>,
 
-    /// CHECK: Drift spot market vault
-    #[account(mut)]
+    /// Drift spot market vault for `market_index`
+    #[account(
+        mut,
+        address = drift::spot_market_vault_address(market_index) @ SilkysigError::InvalidDriftSpotMarketVault
+    )]
     pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Drift spot market
-    #[account(mut)]
+    /// CHECK: Verified by PDA derivation
+    #[account(
+        mut,
+        address = drift::spot_market_address(market_index) @ SilkysigError::InvalidDriftSpotMarket
+    )]
     pub drift_spot_market: AccountInfo<'info>,
 
     /// CHECK: Drift oracle
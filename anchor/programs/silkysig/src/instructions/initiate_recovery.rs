@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Guardians propose a replacement owner; completes after `recovery_delay` unless cancelled.
+pub fn initiate_recovery(ctx: Context<InitiateRecovery>, new_owner: Pubkey) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.require_guardian_approval(&ctx.accounts.guardian.key(), ctx.remaining_accounts)?;
+
+    require!(account.recovery_owner.is_none(), SilkysigError::RecoveryAlreadyPending);
+    require!(
+        new_owner != Pubkey::default() && new_owner != account.owner,
+        SilkysigError::InvalidNewOwner
+    );
+
+    let clock = Clock::get()?;
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(account.recovery_delay)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    account.recovery_owner = Some(new_owner);
+    account.recovery_eta = executable_at;
+
+    emit!(RecoveryInitiated {
+        account: account.key(),
+        initiator: ctx.accounts.guardian.key(),
+        new_owner,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitiateRecovery<'info> {
+    /// Guardian — further guardian signatures go in remaining accounts
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct RecoveryInitiated {
+    pub account: Pubkey,
+    pub initiator: Pubkey,
+    pub new_owner: Pubkey,
+    pub executable_at: i64,
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use crate::{drift::{self, DRIFT_ACCOUNTS_LEN}, state::*, errors::*, constants::*};
+
+/// Move the account's Drift position to another spot market for the same mint.
+///
+/// Remaining accounts: the `DriftAccounts` for the current market, then the new
+/// market's spot_market_vault, oracle and spot_market.
+pub fn migrate_drift_market<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, MigrateDriftMarket<'info>>,
+    new_market_index: u16,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.authorize_action(&ctx.accounts.signer.key(), OPERATOR_MANAGE_VENUE, ctx.remaining_accounts)?;
+
+    let old_market_index = account
+        .drift_market_index
+        .ok_or(SilkysigError::DriftNotInitialized)?;
+    require!(
+        new_market_index != old_market_index,
+        SilkysigError::InvalidDriftSpotMarket
+    );
+
+    let remaining = ctx.remaining_accounts;
+    require!(
+        remaining.len() >= DRIFT_ACCOUNTS_LEN + 3,
+        SilkysigError::MissingDriftAccounts
+    );
+    let new_spot_market_vault = &remaining[DRIFT_ACCOUNTS_LEN];
+    let new_oracle = &remaining[DRIFT_ACCOUNTS_LEN + 1];
+    let new_spot_market = &remaining[DRIFT_ACCOUNTS_LEN + 2];
+
+    // The new market must take the same mint the account holds
+    require!(
+        new_spot_market.owner == &drift_cpi::ID,
+        SilkysigError::InvalidDriftProgram
+    );
+    let spot_market = drift_cpi::SpotMarket::try_deserialize(
+        &mut &new_spot_market.try_borrow_data()?[..],
+    )?;
+    require!(
+        spot_market.market_index == new_market_index && spot_market.mint == account.mint,
+        SilkysigError::InvalidDriftSpotMarket
+    );
+
+    // Withdraw everything from the current market into the ATA
+    let balance_before = ctx.accounts.account_token_account.amount;
+    drift::withdraw(
+        account,
+        remaining,
+        ctx.accounts.account_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        u64::MAX,
+        true,
+    )?;
+    ctx.accounts.account_token_account.reload()?;
+    let migrated = ctx
+        .accounts
+        .account_token_account
+        .amount
+        .saturating_sub(balance_before);
+
+    // Switch to the new market's bumps; its vault and market are checked against them
+    account.drift_bumps = DriftBumps::find(&account.key(), new_market_index);
+
+    // Re-deposit into the new market: same Drift accounts with the new market's vault, oracle and market
+    if migrated > 0 {
+        let deposit_accounts = [
+            remaining[0].clone(),
+            remaining[1].clone(),
+            remaining[2].clone(),
+            new_spot_market_vault.clone(),
+            remaining[4].clone(),
+            remaining[5].clone(),
+            new_oracle.clone(),
+            new_spot_market.clone(),
+        ];
+        drift::deposit(
+            account,
+            &deposit_accounts,
+            ctx.accounts.account_token_account.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            new_market_index,
+            migrated,
+        )?;
+    }
+
+    account.drift_market_index = Some(new_market_index);
+
+    emit!(DriftMarketMigrated {
+        account: account.key(),
+        owner: account.owner,
+        old_market_index,
+        new_market_index,
+        amount: migrated,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateDriftMarket<'info> {
+    /// Owner, co-owner, or operator with `OPERATOR_MANAGE_VENUE` — further co-owner signatures go in remaining accounts after the Drift accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// Account PDA's token account (funds pass through here between markets)
+    #[account(
+        mut,
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct DriftMarketMigrated {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub old_market_index: u16,
+    pub new_market_index: u16,
+    pub amount: u64,
+}
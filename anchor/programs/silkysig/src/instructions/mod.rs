@@ -1,17 +1,63 @@
+mod accept_ownership;
+mod account_to_account;
 mod add_operator;
+mod approve_pending_transfer;
+mod cancel_escrow_from_account;
+mod cancel_recovery;
+mod cancel_recurring_payment;
+mod cleanup_drift_user;
 mod close_account;
+mod complete_recovery;
 mod create_account;
+mod create_escrow_from_account;
+mod create_recurring_payment;
+mod deny_pending_transfer;
 mod deposit;
+mod disable_drift;
+mod execute_recurring;
 mod init_drift_user;
+mod initiate_recovery;
+mod migrate_drift_market;
+mod rebalance;
 mod remove_operator;
-mod toggle_pause;
+mod request_transfer;
+mod set_guardians;
+mod set_liquidity_buffer;
+mod set_owner_set;
+mod set_pause;
+mod set_pause_guardian;
+mod sync_yield;
 mod transfer_from_account;
+mod transfer_ownership;
 
+pub use accept_ownership::*;
+pub use account_to_account::*;
 pub use add_operator::*;
+pub use approve_pending_transfer::*;
+pub use cancel_escrow_from_account::*;
+pub use cancel_recovery::*;
+pub use cancel_recurring_payment::*;
+pub use cleanup_drift_user::*;
 pub use close_account::*;
+pub use complete_recovery::*;
 pub use create_account::*;
+pub use create_escrow_from_account::*;
+pub use create_recurring_payment::*;
+pub use deny_pending_transfer::*;
 pub use deposit::*;
+pub use disable_drift::*;
+pub use execute_recurring::*;
 pub use init_drift_user::*;
+pub use initiate_recovery::*;
+pub use migrate_drift_market::*;
+pub use rebalance::*;
 pub use remove_operator::*;
-pub use toggle_pause::*;
+pub use request_transfer::*;
+pub use set_guardians::*;
+pub use set_liquidity_buffer::*;
+pub use set_owner_set::*;
+pub use set_pause::*;
+pub use set_pause_guardian::*;
+pub use sync_yield::*;
 pub use transfer_from_account::*;
+pub use transfer_ownership::*;
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
+
+/// Move funds between the ATA and the yield venue so the ATA holds exactly the
+/// liquidity buffer target (permissionless crank).
+///
+/// Remaining accounts: the adapter's venue accounts (Drift: the 8 withdraw accounts).
+pub fn rebalance<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, Rebalance<'info>>,
+) -> Result<()> {
+    let account = &ctx.accounts.silk_account;
+
+    let adapter = yield_adapter::load(AdapterContext {
+        silk_account: account,
+        remaining_accounts: ctx.remaining_accounts,
+        account_token_account: ctx.accounts.account_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })?
+    .ok_or(SilkysigError::NoYieldAdapter)?;
+
+    let settled = yield_adapter::settle_buffer(
+        adapter.as_ref(),
+        ctx.accounts.account_token_account.amount,
+        |total_value| account.buffer_target(total_value),
+    )?;
+
+    emit!(Rebalanced {
+        account: account.key(),
+        buffer_target: settled.buffer_target,
+        deposited: settled.deposited,
+        withdrawn: settled.withdrawn,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    /// Anyone can call this (permissionless)
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// Account PDA's token account (the liquidity buffer)
+    #[account(
+        mut,
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct Rebalanced {
+    pub account: Pubkey,
+    pub buffer_target: u64,
+    pub deposited: u64,
+    pub withdrawn: u64,
+}
@@ -3,6 +3,7 @@ use crate::{state::*, errors::*, constants::*};
 
 pub fn remove_operator(ctx: Context<RemoveOperator>, operator: Pubkey) -> Result<()> {
     let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
 
     let idx = account.find_operator(&operator)
         .ok_or(SilkysigError::OperatorNotFound)?;
@@ -25,14 +26,14 @@ pub fn remove_operator(ctx: Context<RemoveOperator>, operator: Pubkey) -> Result
 
 #[derive(Accounts)]
 pub struct RemoveOperator<'info> {
+    /// Owner or co-owner — further co-owner signatures go in remaining accounts
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub signer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [ACCOUNT_SEED, owner.key().as_ref()],
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
         bump = silk_account.bump,
-        has_one = owner,
     )]
     pub silk_account: Box<Account<'info, SilkAccount>>,
 }
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Operator queues a transfer for owner approval (e.g. above its per_tx_limit).
+pub fn request_transfer(ctx: Context<RequestTransfer>, amount: u64, expires_at: i64) -> Result<()> {
+    require!(amount > 0, SilkysigError::AmountMustBePositive);
+
+    let account = &mut ctx.accounts.silk_account;
+    let operator_key = ctx.accounts.operator.key();
+
+    account.require_operator_permission(&operator_key, OPERATOR_TRANSFER)?;
+
+    let clock = Clock::get()?;
+    require!(
+        expires_at > clock.unix_timestamp
+            && expires_at - clock.unix_timestamp <= MAX_PENDING_TRANSFER_TTL,
+        SilkysigError::InvalidExpiry
+    );
+
+    let id = account.next_pending_transfer_id;
+    account.next_pending_transfer_id = id
+        .checked_add(1)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    let pending = &mut ctx.accounts.pending_transfer;
+    pending.version = 1;
+    pending.bump = ctx.bumps.pending_transfer;
+    pending.account = account.key();
+    pending.id = id;
+    pending.requester = operator_key;
+    pending.recipient = ctx.accounts.recipient.key();
+    pending.amount = amount;
+    pending.created_at = clock.unix_timestamp;
+    pending.expires_at = expires_at;
+    pending._reserved = [0u8; 32];
+
+    emit!(TransferRequested {
+        account: account.key(),
+        pending_transfer: pending.key(),
+        id,
+        operator: operator_key,
+        recipient: pending.recipient,
+        amount,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestTransfer<'info> {
+    /// Operator — pays rent for the request (refunded on approve/deny)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = PendingTransfer::SPACE,
+        seeds = [
+            PENDING_TRANSFER_SEED,
+            silk_account.key().as_ref(),
+            silk_account.next_pending_transfer_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub pending_transfer: Box<Account<'info, PendingTransfer>>,
+
+    /// CHECK: Recipient address — only stored for the later ATA derivation
+    pub recipient: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct TransferRequested {
+    pub account: Pubkey,
+    pub pending_transfer: Pubkey,
+    pub id: u64,
+    pub operator: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+}
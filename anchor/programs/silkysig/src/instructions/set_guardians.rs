@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+use super::cancel_recovery::RecoveryCancelled;
+
+/// Configure recovery guardians (empty list disables recovery).
+///
+/// A recovery in flight was approved by the old guardian set, so it is cancelled.
+pub fn set_guardians(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+    recovery_delay: i64,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+
+    require!(guardians.len() <= MAX_GUARDIANS, SilkysigError::InvalidGuardianSet);
+
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(
+            *guardian != Pubkey::default() && *guardian != account.owner,
+            SilkysigError::InvalidGuardianSet
+        );
+        require!(!guardians[..i].contains(guardian), SilkysigError::InvalidGuardianSet);
+    }
+
+    if guardians.is_empty() {
+        require!(threshold == 0, SilkysigError::InvalidGuardianSet);
+    } else {
+        require!(
+            threshold >= 1 && threshold as usize <= guardians.len(),
+            SilkysigError::InvalidGuardianSet
+        );
+        require!(
+            recovery_delay >= MIN_RECOVERY_DELAY,
+            SilkysigError::InvalidGuardianSet
+        );
+    }
+
+    let mut slots = [Pubkey::default(); MAX_GUARDIANS];
+    slots[..guardians.len()].copy_from_slice(&guardians);
+
+    account.guardians = slots;
+    account.guardian_count = guardians.len() as u8;
+    account.guardian_threshold = threshold;
+    account.recovery_delay = if guardians.is_empty() { 0 } else { recovery_delay };
+
+    if let Some(new_owner) = account.recovery_owner.take() {
+        account.recovery_eta = 0;
+
+        emit!(RecoveryCancelled {
+            account: account.key(),
+            owner: account.owner,
+            new_owner,
+        });
+    }
+
+    emit!(GuardiansUpdated {
+        account: account.key(),
+        owner: account.owner,
+        guardians,
+        threshold,
+        recovery_delay: account.recovery_delay,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    /// Owner or co-owner — further co-owner signatures go in remaining accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct GuardiansUpdated {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub recovery_delay: i64,
+}
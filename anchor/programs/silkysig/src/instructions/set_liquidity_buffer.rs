@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Set how much of the account stays liquid in its ATA instead of the yield venue.
+///
+/// Either `buffer_bps` of total value or an absolute `buffer_amount` — not both.
+/// Zero for both forwards everything to the venue.
+pub fn set_liquidity_buffer(
+    ctx: Context<SetLiquidityBuffer>,
+    buffer_bps: u16,
+    buffer_amount: u64,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.authorize_action(&ctx.accounts.signer.key(), OPERATOR_SET_BUFFER, ctx.remaining_accounts)?;
+
+    require!(
+        buffer_bps <= MAX_BPS && (buffer_bps == 0 || buffer_amount == 0),
+        SilkysigError::InvalidLiquidityBuffer
+    );
+
+    account.buffer_bps = buffer_bps;
+    account.buffer_amount = buffer_amount;
+
+    emit!(LiquidityBufferUpdated {
+        account: account.key(),
+        owner: account.owner,
+        buffer_bps,
+        buffer_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLiquidityBuffer<'info> {
+    /// Owner, co-owner, or operator with `OPERATOR_SET_BUFFER` — further co-owner signatures go in remaining accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct LiquidityBufferUpdated {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub buffer_bps: u16,
+    pub buffer_amount: u64,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+pub fn set_owner_set(ctx: Context<SetOwnerSet>, co_owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+
+    require!(co_owners.len() <= MAX_CO_OWNERS, SilkysigError::InvalidOwnerSet);
+
+    for (i, co_owner) in co_owners.iter().enumerate() {
+        require!(
+            *co_owner != Pubkey::default() && *co_owner != account.owner,
+            SilkysigError::InvalidOwnerSet
+        );
+        require!(!co_owners[..i].contains(co_owner), SilkysigError::InvalidOwnerSet);
+    }
+
+    // Empty set reverts to single-owner control; otherwise 1..=N approvals (owner counts as one)
+    if co_owners.is_empty() {
+        require!(threshold <= 1, SilkysigError::InvalidOwnerSet);
+    } else {
+        require!(
+            threshold >= 1 && threshold as usize <= co_owners.len() + 1,
+            SilkysigError::InvalidOwnerSet
+        );
+    }
+
+    let mut slots = [Pubkey::default(); MAX_CO_OWNERS];
+    slots[..co_owners.len()].copy_from_slice(&co_owners);
+
+    account.co_owners = slots;
+    account.co_owner_count = co_owners.len() as u8;
+    account.owner_threshold = if co_owners.is_empty() { 0 } else { threshold };
+
+    emit!(OwnerSetUpdated {
+        account: account.key(),
+        owner: account.owner,
+        co_owners,
+        threshold: account.owner_threshold,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOwnerSet<'info> {
+    /// Owner or co-owner — further co-owner signatures go in remaining accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct OwnerSetUpdated {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub co_owners: Vec<Pubkey>,
+    pub threshold: u8,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Set the circuit breaker explicitly (idempotent, safe under racing transactions).
+/// The owner set may pause or unpause; the pause guardian and operators granted
+/// `OPERATOR_PAUSE` may only pause.
+pub fn set_pause<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SetPause<'info>>,
+    is_paused: bool,
+    reason: Option<u8>,
+) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    let signer_key = ctx.accounts.signer.key();
+
+    if account.is_owner_key(&signer_key) {
+        account.require_owner_approval(&signer_key, ctx.remaining_accounts)?;
+    } else if account.pause_guardian == Some(signer_key) {
+        require!(is_paused, SilkysigError::GuardianCannotUnpause);
+    } else if let Some(idx) = account.find_operator(&signer_key) {
+        require!(
+            account.operators[idx].has_permission(OPERATOR_PAUSE),
+            SilkysigError::OperatorNotPermitted
+        );
+        require!(is_paused, SilkysigError::OperatorCannotUnpause);
+    } else {
+        return Err(SilkysigError::Unauthorized.into());
+    }
+
+    account.is_paused = is_paused;
+
+    emit!(AccountPauseToggled {
+        account: account.key(),
+        owner: account.owner,
+        signer: signer_key,
+        is_paused,
+        reason,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    /// Owner, co-owner, pause guardian, or operator with `OPERATOR_PAUSE`
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct AccountPauseToggled {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub signer: Pubkey,
+    pub is_paused: bool,
+    pub reason: Option<u8>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*};
+
+/// Designate (or clear) a key that can pause the account but never unpause it.
+pub fn set_pause_guardian(ctx: Context<SetPauseGuardian>, pause_guardian: Option<Pubkey>) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+
+    account.pause_guardian = pause_guardian;
+
+    emit!(PauseGuardianUpdated {
+        account: account.key(),
+        owner: account.owner,
+        pause_guardian,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPauseGuardian<'info> {
+    /// Owner or co-owner — further co-owner signatures go in remaining accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct PauseGuardianUpdated {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub pause_guardian: Option<Pubkey>,
+}
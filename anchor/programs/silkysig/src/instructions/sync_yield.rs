@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use crate::{drift, state::*, errors::*, constants::*};
+
+/// Record the account's current value and accrued Drift yield (permissionless crank).
+///
+/// Value uses the spot market's cumulative deposit interest as of Drift's last
+/// interest update, so it may trail the live balance slightly.
+pub fn sync_yield(ctx: Context<SyncYield>) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+
+    let market_index = account
+        .drift_market_index
+        .ok_or(SilkysigError::DriftNotInitialized)?;
+    drift::require_spot_market(account, &ctx.accounts.drift_spot_market, market_index)?;
+
+    let drift_value = drift::deposit_token_amount(
+        &ctx.accounts.drift_user,
+        &ctx.accounts.drift_spot_market,
+        market_index,
+    )?;
+
+    let total_value = drift_value
+        .checked_add(ctx.accounts.account_token_account.amount)
+        .ok_or(SilkysigError::MathOverflow)?;
+
+    // Value above principal is unrealized yield; add what withdrawals already spent
+    let cumulative_yield = total_value
+        .saturating_sub(account.principal_balance)
+        .checked_add(account.yield_withdrawn)
+        .ok_or(SilkysigError::MathOverflow)?;
+    let slot = Clock::get()?.slot;
+
+    account.last_synced_value = total_value;
+    account.cumulative_yield = cumulative_yield;
+    account.last_yield_sync_slot = slot;
+
+    emit!(YieldSynced {
+        account: account.key(),
+        drift_value,
+        total_value,
+        principal_balance: account.principal_balance,
+        yield_withdrawn: account.yield_withdrawn,
+        cumulative_yield,
+        slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SyncYield<'info> {
+    /// Anyone can call this (permissionless)
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+
+    /// Account PDA's token account (uninvested balance)
+    #[account(
+        associated_token::mint = silk_account.mint,
+        associated_token::authority = silk_account,
+        associated_token::token_program = token_program
+    )]
+    pub account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Must be the account's Drift user; layout validated when read
+    #[account(
+        constraint = Some(drift_user.key()) == silk_account.drift_user @ SilkysigError::InvalidDriftUser
+    )]
+    pub drift_user: AccountInfo<'info>,
+
+    /// CHECK: Drift spot market for the account's market index; PDA checked against the recorded bump
+    pub drift_spot_market: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct YieldSynced {
+    pub account: Pubkey,
+    pub drift_value: u64,
+    pub total_value: u64,
+    pub principal_balance: u64,
+    pub yield_withdrawn: u64,
+    pub cumulative_yield: u64,
+    pub slot: u64,
+}
@@ -2,10 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{
-        transfer_checked, TransferChecked, Mint, TokenAccount, TokenInterface,
+        TransferChecked, Mint, TokenAccount, TokenInterface,
     },
 };
-use crate::{debug_msg, state::*, errors::*, constants::*};
+use handshake::token_extensions;
+use crate::{state::*, errors::*, constants::*, yield_adapter::{self, AdapterContext}};
 
 pub fn transfer_from_account<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, TransferFromAccount<'info>>,
@@ -16,97 +17,45 @@ pub fn transfer_from_account<'a, 'b, 'c, 'info>(
     let account = &mut ctx.accounts.silk_account;
     let signer_key = ctx.accounts.signer.key();
 
-    // Determine signer role for access control and event enrichment
-    let is_owner = signer_key == account.owner;
-
-    if is_owner {
-        // Owner: no policy checks
-    } else if let Some(idx) = account.find_operator(&signer_key) {
-        // Operator: enforce policies
-        require!(!account.is_paused, SilkysigError::AccountPaused);
-
-        let operator = &account.operators[idx];
-        // per_tx_limit == 0 means unlimited
-        if operator.per_tx_limit > 0 {
-            require!(amount <= operator.per_tx_limit, SilkysigError::ExceedsPerTxLimit);
-        }
-    } else {
-        return Err(SilkysigError::Unauthorized.into());
-    }
-
-    // If Drift is initialized, withdraw tokens from Drift first
-    if account.drift_user.is_some() {
-        let remaining = &ctx.remaining_accounts;
-        require!(remaining.len() >= 8, SilkysigError::MissingDriftAccounts);
-
-        let drift_state = &remaining[0];
-        let drift_user = &remaining[1];
-        let drift_user_stats = &remaining[2];
-        let drift_spot_market_vault = &remaining[3];
-        let drift_signer = &remaining[4];
-        let drift_program = &remaining[5];
-        let drift_oracle = &remaining[6];
-        let drift_spot_market = &remaining[7];
-
-        // Validate drift program address
-        require!(
-            drift_program.key() == drift_cpi::ID,
-            SilkysigError::InvalidDriftProgram
-        );
-
-        // Validate drift user matches account's stored key
-        require!(
-            drift_user.key() == account.drift_user.unwrap(),
-            SilkysigError::InvalidDriftUser
-        );
-
-        let market_index = account.drift_market_index.unwrap();
-
-        let owner_key = account.owner;
-        let account_seeds = &[
-            ACCOUNT_SEED,
-            owner_key.as_ref(),
-            &[account.bump],
-        ];
-        let pda_signer_seeds = &[&account_seeds[..]];
-
-        let withdraw_accounts = drift_cpi::cpi::accounts::Withdraw {
-            state: drift_state.to_account_info(),
-            user: drift_user.to_account_info(),
-            user_stats: drift_user_stats.to_account_info(),
-            authority: account.to_account_info(),
-            spot_market_vault: drift_spot_market_vault.to_account_info(),
-            drift_signer: drift_signer.to_account_info(),
-            user_token_account: ctx.accounts.account_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        };
-
-        let drift_remaining = vec![
-            drift_oracle.to_account_info(),
-            drift_spot_market.to_account_info(),
-        ];
-
-        let withdraw_ctx = CpiContext::new_with_signer(
-            drift_program.to_account_info(),
-            withdraw_accounts,
-            pda_signer_seeds,
-        )
-        .with_remaining_accounts(drift_remaining);
-
-        match drift_cpi::cpi::withdraw(withdraw_ctx, market_index, amount, false) {
-            Ok(_) => {}
-            Err(_e) => {
-                debug_msg!("Drift withdraw failed: {:?}", _e);
-                return Err(SilkysigError::DriftWithdrawFailed.into());
-            }
+    let adapter = yield_adapter::load(AdapterContext {
+        silk_account: account,
+        remaining_accounts: ctx.remaining_accounts,
+        account_token_account: ctx.accounts.account_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    })?;
+
+    // Value before the withdrawal decides how much of it is yield vs principal
+    let current_value = yield_adapter::account_value(
+        adapter.as_deref(),
+        ctx.accounts.account_token_account.amount,
+    )?;
+
+    // Owner set or operator within policy; the role also enriches the event
+    let is_owner = account.authorize_spend(
+        &signer_key,
+        OPERATOR_TRANSFER,
+        amount,
+        current_value,
+        ctx.remaining_accounts,
+    )?;
+
+    // Pay from the liquidity buffer; only the shortfall comes out of the venue
+    let shortfall = amount.saturating_sub(ctx.accounts.account_token_account.amount);
+    if let Some(adapter) = &adapter {
+        if shortfall > 0 {
+            adapter.withdraw(shortfall)?;
         }
     }
+    // Release the adapter's borrow of the account before updating it
+    drop(adapter);
 
     // Transfer tokens from account PDA's ATA to recipient's ATA
-    let owner_key = account.owner;
+    let creator_key = account.creator;
+    let mint_key = account.mint;
     let account_seeds = &[
         ACCOUNT_SEED,
-        owner_key.as_ref(),
+        creator_key.as_ref(),
+        mint_key.as_ref(),
         &[account.bump],
     ];
     let signer_seeds = &[&account_seeds[..]];
@@ -122,13 +71,14 @@ pub fn transfer_from_account<'a, 'b, 'c, 'info>(
         transfer_accounts,
         signer_seeds,
     );
-    transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    token_extensions::transfer_checked(
+        cpi_ctx,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
 
-    // Update principal balance (checked to surface accounting bugs)
-    account.principal_balance = account
-        .principal_balance
-        .checked_sub(amount)
-        .ok_or(SilkysigError::MathOverflow)?;
+    let (from_yield, from_principal) = account.record_withdrawal(amount, current_value)?;
 
     emit!(TransferExecuted {
         account: ctx.accounts.silk_account.key(),
@@ -136,6 +86,8 @@ pub fn transfer_from_account<'a, 'b, 'c, 'info>(
         recipient: ctx.accounts.recipient.key(),
         mint: ctx.accounts.mint.key(),
         amount,
+        from_yield,
+        from_principal,
         is_owner,
     });
 
@@ -151,7 +103,7 @@ pub struct TransferFromAccount<'info> {
     /// The Silk account to transfer from
     #[account(
         mut,
-        seeds = [ACCOUNT_SEED, silk_account.owner.as_ref()],
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
         bump = silk_account.bump,
     )]
     pub silk_account: Box<Account<'info, SilkAccount>>,
@@ -196,5 +148,7 @@ pub struct TransferExecuted {
     pub recipient: Pubkey,
     pub mint: Pubkey,
     pub amount: u64,
+    pub from_yield: u64,
+    pub from_principal: u64,
     pub is_owner: bool,
 }
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+/// Propose a new owner (None cancels a pending proposal).
+/// The account and its PDA are preserved; the new owner must call `accept_ownership`.
+pub fn transfer_ownership(ctx: Context<TransferOwnership>, new_owner: Option<Pubkey>) -> Result<()> {
+    let account = &mut ctx.accounts.silk_account;
+    account.require_owner_approval(&ctx.accounts.signer.key(), ctx.remaining_accounts)?;
+
+    if let Some(new_owner) = new_owner {
+        require!(
+            new_owner != Pubkey::default() && new_owner != account.owner,
+            SilkysigError::InvalidNewOwner
+        );
+    }
+
+    account.pending_owner = new_owner;
+
+    emit!(OwnershipTransferProposed {
+        account: account.key(),
+        owner: account.owner,
+        pending_owner: new_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferOwnership<'info> {
+    /// Owner or co-owner — further co-owner signatures go in remaining accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ACCOUNT_SEED, silk_account.creator.as_ref(), silk_account.mint.as_ref()],
+        bump = silk_account.bump,
+    )]
+    pub silk_account: Box<Account<'info, SilkAccount>>,
+}
+
+#[event]
+pub struct OwnershipTransferProposed {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+}
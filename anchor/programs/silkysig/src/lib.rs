@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 
 mod constants;
+mod drift;
 mod errors;
 mod instructions;
 mod state;
+mod yield_adapter;
 
 use instructions::*;
 use state::*;
@@ -44,18 +46,131 @@ pub mod silkysig {
         instructions::transfer_from_account(ctx, amount)
     }
 
-    pub fn toggle_pause(ctx: Context<TogglePause>) -> Result<()> {
-        instructions::toggle_pause(ctx)
+    pub fn account_to_account<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, AccountToAccount<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::account_to_account(ctx, amount)
+    }
+
+    pub fn create_escrow_from_account<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CreateEscrowFromAccount<'info>>,
+        recipient: Pubkey,
+        nonce: u64,
+        amount: u64,
+        memo: String,
+        claimable_after: i64,
+        claimable_until: i64,
+    ) -> Result<()> {
+        instructions::create_escrow_from_account(
+            ctx,
+            recipient,
+            nonce,
+            amount,
+            memo,
+            claimable_after,
+            claimable_until,
+        )
+    }
+
+    pub fn cancel_escrow_from_account<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CancelEscrowFromAccount<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_escrow_from_account(ctx)
+    }
+
+    pub fn request_transfer(ctx: Context<RequestTransfer>, amount: u64, expires_at: i64) -> Result<()> {
+        instructions::request_transfer(ctx, amount, expires_at)
+    }
+
+    pub fn approve_pending_transfer<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ApprovePendingTransfer<'info>>,
+    ) -> Result<()> {
+        instructions::approve_pending_transfer(ctx)
+    }
+
+    pub fn deny_pending_transfer<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DenyPendingTransfer<'info>>,
+    ) -> Result<()> {
+        instructions::deny_pending_transfer(ctx)
+    }
+
+    pub fn create_recurring_payment(
+        ctx: Context<CreateRecurringPayment>,
+        amount: u64,
+        interval: i64,
+        first_due: i64,
+        end_at: Option<i64>,
+        max_catch_up: Option<u16>,
+    ) -> Result<()> {
+        instructions::create_recurring_payment(ctx, amount, interval, first_due, end_at, max_catch_up)
+    }
+
+    pub fn cancel_recurring_payment<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CancelRecurringPayment<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_recurring_payment(ctx)
+    }
+
+    pub fn execute_recurring<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ExecuteRecurring<'info>>,
+    ) -> Result<()> {
+        instructions::execute_recurring(ctx)
+    }
+
+    pub fn set_pause<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SetPause<'info>>,
+        is_paused: bool,
+        reason: Option<u8>,
+    ) -> Result<()> {
+        instructions::set_pause(ctx, is_paused, reason)
+    }
+
+    pub fn set_pause_guardian(ctx: Context<SetPauseGuardian>, pause_guardian: Option<Pubkey>) -> Result<()> {
+        instructions::set_pause_guardian(ctx, pause_guardian)
     }
 
-    pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey, per_tx_limit: Option<u64>) -> Result<()> {
-        instructions::add_operator(ctx, operator, per_tx_limit)
+    pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey, per_tx_limit: Option<u64>, yield_only: bool, permissions: u8) -> Result<()> {
+        instructions::add_operator(ctx, operator, per_tx_limit, yield_only, permissions)
     }
 
     pub fn remove_operator(ctx: Context<RemoveOperator>, operator: Pubkey) -> Result<()> {
         instructions::remove_operator(ctx, operator)
     }
 
+    pub fn set_owner_set(ctx: Context<SetOwnerSet>, co_owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::set_owner_set(ctx, co_owners, threshold)
+    }
+
+    pub fn transfer_ownership(ctx: Context<TransferOwnership>, new_owner: Option<Pubkey>) -> Result<()> {
+        instructions::transfer_ownership(ctx, new_owner)
+    }
+
+    pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+        instructions::accept_ownership(ctx)
+    }
+
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        recovery_delay: i64,
+    ) -> Result<()> {
+        instructions::set_guardians(ctx, guardians, threshold, recovery_delay)
+    }
+
+    pub fn initiate_recovery(ctx: Context<InitiateRecovery>, new_owner: Pubkey) -> Result<()> {
+        instructions::initiate_recovery(ctx, new_owner)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::cancel_recovery(ctx)
+    }
+
+    pub fn complete_recovery(ctx: Context<CompleteRecovery>) -> Result<()> {
+        instructions::complete_recovery(ctx)
+    }
+
     pub fn init_drift_user(
         ctx: Context<InitDriftUser>,
         sub_account_id: u16,
@@ -65,9 +180,48 @@ pub mod silkysig {
         instructions::init_drift_user(ctx, sub_account_id, name, market_index)
     }
 
+    pub fn sync_yield(ctx: Context<SyncYield>) -> Result<()> {
+        instructions::sync_yield(ctx)
+    }
+
+    pub fn set_liquidity_buffer(
+        ctx: Context<SetLiquidityBuffer>,
+        buffer_bps: u16,
+        buffer_amount: u64,
+    ) -> Result<()> {
+        instructions::set_liquidity_buffer(ctx, buffer_bps, buffer_amount)
+    }
+
+    pub fn rebalance<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Rebalance<'info>>,
+    ) -> Result<()> {
+        instructions::rebalance(ctx)
+    }
+
+    pub fn disable_drift<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DisableDrift<'info>>,
+    ) -> Result<()> {
+        instructions::disable_drift(ctx)
+    }
+
+    pub fn migrate_drift_market<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, MigrateDriftMarket<'info>>,
+        new_market_index: u16,
+    ) -> Result<()> {
+        instructions::migrate_drift_market(ctx, new_market_index)
+    }
+
     pub fn close_account<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, CloseAccount<'info>>,
     ) -> Result<()> {
         instructions::close_account(ctx)
     }
+
+    pub fn cleanup_drift_user(
+        ctx: Context<CleanupDriftUser>,
+        creator: Pubkey,
+        mint: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::cleanup_drift_user(ctx, creator, mint)
+    }
 }
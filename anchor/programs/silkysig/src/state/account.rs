@@ -1,7 +1,31 @@
 use anchor_lang::prelude::*;
+use crate::{constants::MAX_BPS, errors::SilkysigError};
 
 pub const MAX_OPERATORS: usize = 3;
 
+/// Operator may move funds out (`transfer_from_account`, `account_to_account`, `request_transfer`)
+pub const OPERATOR_TRANSFER: u8 = 1 << 0;
+/// Operator may tune the liquidity buffer (`set_liquidity_buffer`) without moving funds out
+pub const OPERATOR_SET_BUFFER: u8 = 1 << 1;
+/// Operator may create handshake escrows funded by the account
+pub const OPERATOR_CREATE_ESCROW: u8 = 1 << 2;
+/// Operator may pause the account (never unpause)
+pub const OPERATOR_PAUSE: u8 = 1 << 3;
+/// Operator may enable, disable, or migrate the yield venue (`init_drift_user`,
+/// `disable_drift`, `migrate_drift_market`)
+pub const OPERATOR_MANAGE_VENUE: u8 = 1 << 4;
+pub const OPERATOR_ALL_PERMISSIONS: u8 = OPERATOR_TRANSFER
+    | OPERATOR_SET_BUFFER
+    | OPERATOR_CREATE_ESCROW
+    | OPERATOR_PAUSE
+    | OPERATOR_MANAGE_VENUE;
+pub const MAX_CO_OWNERS: usize = 4;
+pub const MAX_GUARDIANS: usize = 5;
+
+/// Layout version 2. Fields were inserted mid-struct (`creator`, operator permissions)
+/// and the PDA seeds gained the mint, so v2 is not readable as v1 and v1 PDAs do not
+/// re-derive. Moving to v2 is a fresh program deployment: v1 owners close their
+/// accounts on the old program and create new ones; there is no in-place migration.
 #[account]
 pub struct SilkAccount {
     /// Version for future upgrades
@@ -13,7 +37,10 @@ pub struct SilkAccount {
     /// Account owner (human) — full control, bypasses all policies
     pub owner: Pubkey,
 
-    /// Token mint this account holds
+    /// Owner key at creation — used in the PDA seeds, never changes on ownership transfer
+    pub creator: Pubkey,
+
+    /// Token mint this account holds (also part of the PDA seeds)
     pub mint: Pubkey,
 
     /// Circuit breaker — paused accounts block all operator transfers
@@ -34,8 +61,81 @@ pub struct SilkAccount {
     /// Principal balance deposited (for bookkeeping, not yield calculation)
     pub principal_balance: u64,
 
+    /// Additional owner keys that may approve owner-only actions (0-4)
+    pub co_owners: [Pubkey; MAX_CO_OWNERS],
+
+    /// Number of active co-owners
+    pub co_owner_count: u8,
+
+    /// Approvals (owner + co-owners) required for owner-only actions (0 = owner alone)
+    pub owner_threshold: u8,
+
+    /// Proposed new owner awaiting `accept_ownership` (None = no transfer pending)
+    pub pending_owner: Option<Pubkey>,
+
+    /// Recovery guardians — can jointly replace a lost owner key after a timelock
+    pub guardians: [Pubkey; MAX_GUARDIANS],
+
+    /// Number of active guardians (0 = recovery disabled)
+    pub guardian_count: u8,
+
+    /// Guardian signatures required to initiate recovery
+    pub guardian_threshold: u8,
+
+    /// Seconds between recovery initiation and completion (owner can cancel meanwhile)
+    pub recovery_delay: i64,
+
+    /// New owner of an in-flight recovery (None = no recovery pending)
+    pub recovery_owner: Option<Pubkey>,
+
+    /// Unix timestamp after which the pending recovery can be completed
+    pub recovery_eta: i64,
+
+    /// Id for the next `PendingTransfer` requested by an operator
+    pub next_pending_transfer_id: u64,
+
+    /// Incident-response key that can pause but never unpause (None = owner set only)
+    pub pause_guardian: Option<Pubkey>,
+
+    /// Total value (Drift position + ATA) at the last `sync_yield`
+    pub last_synced_value: u64,
+
+    /// Yield earned to date as of the last `sync_yield` (unrealized + withdrawn)
+    pub cumulative_yield: u64,
+
+    /// Slot of the last `sync_yield` (0 = never synced)
+    pub last_yield_sync_slot: u64,
+
+    /// Total yield spent by withdrawals (principal_balance only shrinks once yield is exhausted)
+    pub yield_withdrawn: u64,
+
+    /// Venue idle funds are parked in (Drift uses drift_user/drift_market_index)
+    pub yield_adapter: YieldAdapterKind,
+
+    /// Liquidity buffer kept in the ATA as basis points of total value (used when buffer_amount == 0)
+    pub buffer_bps: u16,
+
+    /// Liquidity buffer kept in the ATA as an absolute token amount (0 = use buffer_bps)
+    pub buffer_amount: u64,
+
+    /// Id assigned to the next RecurringPayment (part of its PDA seeds)
+    pub next_recurring_payment_id: u64,
+
+    /// Bumps of the Drift PDAs for the current market, so they are checked without re-deriving
+    pub drift_bumps: DriftBumps,
+
+    /// Drift user left behind by `disable_drift`, until re-attached or cleaned up
+    pub detached_drift_user: Option<Pubkey>,
+
+    /// Slot this account was created in; RecurringPayments from an earlier account
+    /// at the same address carry a different generation and are never executed
+    pub generation: u64,
+
+    /// RecurringPayments not yet cancelled — the account cannot close while any remain
+    pub active_recurring_payments: u32,
+
     /// Reserved for future fields (avoids realloc migrations)
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 16],
 }
 
 impl SilkAccount {
@@ -43,6 +143,7 @@ impl SilkAccount {
         + 1                     // version
         + 1                     // bump
         + 32                    // owner
+        + 32                    // creator
         + 32                    // mint
         + 1                     // is_paused
         + 1                     // operator_count
@@ -50,7 +151,61 @@ impl SilkAccount {
         + 33                    // drift_user (Option<Pubkey>)
         + 3                     // drift_market_index (Option<u16>)
         + 8                     // principal_balance
-        + 64;                   // _reserved
+        + (MAX_CO_OWNERS * 32)  // co_owners
+        + 1                     // co_owner_count
+        + 1                     // owner_threshold
+        + 33                    // pending_owner (Option<Pubkey>)
+        + (MAX_GUARDIANS * 32)  // guardians
+        + 1                     // guardian_count
+        + 1                     // guardian_threshold
+        + 8                     // recovery_delay
+        + 33                    // recovery_owner (Option<Pubkey>)
+        + 8                     // recovery_eta
+        + 8                     // next_pending_transfer_id
+        + 33                    // pause_guardian (Option<Pubkey>)
+        + 8                     // last_synced_value
+        + 8                     // cumulative_yield
+        + 8                     // last_yield_sync_slot
+        + 8                     // yield_withdrawn
+        + 1                     // yield_adapter
+        + 2                     // buffer_bps
+        + 8                     // buffer_amount
+        + 8                     // next_recurring_payment_id
+        + DriftBumps::SPACE     // drift_bumps
+        + 33                    // detached_drift_user (Option<Pubkey>)
+        + 8                     // generation
+        + 4                     // active_recurring_payments
+        + 16;                   // _reserved
+
+    /// Split a withdrawal of `amount` into (from_yield, from_principal) given the
+    /// account's `current_value` before the withdrawal, and record it.
+    /// Yield is spent first so principal is preserved for as long as possible.
+    pub fn record_withdrawal(&mut self, amount: u64, current_value: u64) -> Result<(u64, u64)> {
+        let available_yield = current_value.saturating_sub(self.principal_balance);
+        // Value is read from Drift's last interest update, so the live balance can be
+        // slightly higher — anything principal can't cover is yield as well
+        let from_principal = amount
+            .saturating_sub(available_yield)
+            .min(self.principal_balance);
+        let from_yield = amount - from_principal;
+
+        self.principal_balance -= from_principal;
+        self.yield_withdrawn = self
+            .yield_withdrawn
+            .checked_add(from_yield)
+            .ok_or(SilkysigError::MathOverflow)?;
+
+        Ok((from_yield, from_principal))
+    }
+
+    /// Balance to keep liquid in the ATA given the account's total value.
+    pub fn buffer_target(&self, total_value: u64) -> u64 {
+        if self.buffer_amount > 0 {
+            self.buffer_amount
+        } else {
+            (total_value as u128 * self.buffer_bps as u128 / MAX_BPS as u128) as u64
+        }
+    }
 
     /// Find the operator slot index for a given pubkey.
     /// Returns None if the pubkey is not an active operator.
@@ -62,6 +217,175 @@ impl SilkAccount {
         }
         None
     }
+
+    /// Whether the pubkey is the owner or an active co-owner.
+    pub fn is_owner_key(&self, pubkey: &Pubkey) -> bool {
+        *pubkey == self.owner
+            || self.co_owners[..self.co_owner_count as usize].contains(pubkey)
+    }
+
+    /// Require enough owner-set signatures on the transaction for an owner-only action.
+    /// Approvals are counted from the instruction signer plus any signing remaining accounts.
+    pub fn require_owner_approval(
+        &self,
+        signer: &Pubkey,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        let approvals = count_signed(&[self.owner], signer, remaining_accounts)
+            + count_signed(
+                &self.co_owners[..self.co_owner_count as usize],
+                signer,
+                remaining_accounts,
+            );
+
+        require!(approvals > 0, SilkysigError::Unauthorized);
+        require!(
+            approvals >= self.owner_threshold.max(1) as usize,
+            SilkysigError::InsufficientOwnerApprovals
+        );
+        Ok(())
+    }
+
+    /// Check `signer` may move `amount` out of the account: the owner set (at its
+    /// threshold), or an unpaused operator granted `permission` and within its policy.
+    /// `current_value` is the account's total value before the withdrawal. Returns
+    /// whether the owner set signed.
+    pub fn authorize_spend(
+        &self,
+        signer: &Pubkey,
+        permission: u8,
+        amount: u64,
+        current_value: u64,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<bool> {
+        if self.is_owner_key(signer) {
+            // Owner: no policy checks, but a configured owner set must reach its threshold
+            self.require_owner_approval(signer, remaining_accounts)?;
+            return Ok(true);
+        }
+
+        let operator = self.require_operator_permission(signer, permission)?;
+        // per_tx_limit == 0 means unlimited
+        if operator.per_tx_limit > 0 {
+            require!(amount <= operator.per_tx_limit, SilkysigError::ExceedsPerTxLimit);
+        }
+
+        if operator.yield_only {
+            require!(
+                amount <= current_value.saturating_sub(self.principal_balance),
+                SilkysigError::ExceedsAvailableYield
+            );
+        }
+
+        Ok(false)
+    }
+
+    /// Check `signer` may take an action gated by `permission`: the owner set (at its
+    /// threshold), or an operator granted it. Returns whether the owner set signed.
+    pub fn authorize_action(
+        &self,
+        signer: &Pubkey,
+        permission: u8,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<bool> {
+        if self.is_owner_key(signer) {
+            self.require_owner_approval(signer, remaining_accounts)?;
+            return Ok(true);
+        }
+
+        self.require_operator_permission(signer, permission)?;
+        Ok(false)
+    }
+
+    /// Require `signer` to be an operator granted `permission` on an unpaused account.
+    pub fn require_operator_permission(
+        &self,
+        signer: &Pubkey,
+        permission: u8,
+    ) -> Result<&OperatorSlot> {
+        let idx = self.find_operator(signer).ok_or(SilkysigError::Unauthorized)?;
+        require!(!self.is_paused, SilkysigError::AccountPaused);
+
+        let operator = &self.operators[idx];
+        require!(operator.has_permission(permission), SilkysigError::OperatorNotPermitted);
+        Ok(operator)
+    }
+
+    /// Require `guardian_threshold` guardian signatures on the transaction.
+    pub fn require_guardian_approval(
+        &self,
+        signer: &Pubkey,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        require!(self.guardian_count > 0, SilkysigError::RecoveryNotConfigured);
+
+        let approvals = count_signed(
+            &self.guardians[..self.guardian_count as usize],
+            signer,
+            remaining_accounts,
+        );
+
+        require!(approvals > 0, SilkysigError::Unauthorized);
+        require!(
+            approvals >= self.guardian_threshold as usize,
+            SilkysigError::InsufficientGuardianApprovals
+        );
+        Ok(())
+    }
+
+    /// Hand the account to a new owner, clearing any pending transfer or recovery.
+    /// A new owner who was a co-owner leaves the co-owner list; the threshold is
+    /// clamped so the owner set stays satisfiable.
+    pub fn set_owner(&mut self, new_owner: Pubkey) {
+        let count = self.co_owner_count as usize;
+        if let Some(idx) = self.co_owners[..count].iter().position(|k| *k == new_owner) {
+            self.co_owners[idx] = self.co_owners[count - 1];
+            self.co_owners[count - 1] = Pubkey::default();
+            self.co_owner_count -= 1;
+        }
+        if self.co_owner_count == 0 {
+            self.owner_threshold = 0;
+        } else {
+            self.owner_threshold = self.owner_threshold.min(self.co_owner_count + 1);
+        }
+
+        self.owner = new_owner;
+        self.pending_owner = None;
+        self.recovery_owner = None;
+        self.recovery_eta = 0;
+    }
+}
+
+/// Count how many of `keys` signed the transaction, either as the instruction
+/// signer or as a signing remaining account.
+fn count_signed(keys: &[Pubkey], signer: &Pubkey, remaining_accounts: &[AccountInfo]) -> usize {
+    keys.iter()
+        .filter(|key| {
+            *key == signer
+                || remaining_accounts
+                    .iter()
+                    .any(|acc| acc.is_signer && acc.key == *key)
+        })
+        .count()
+}
+
+/// Bumps of the account's Drift PDAs, recorded by `init_drift_user` and `migrate_drift_market`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct DriftBumps {
+    pub user_stats: u8,
+    pub spot_market: u8,
+    pub spot_market_vault: u8,
+}
+
+impl DriftBumps {
+    pub const SPACE: usize = 3;
+}
+
+/// Lending venue behind the account's `YieldAdapter`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum YieldAdapterKind {
+    None,
+    Drift,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
@@ -72,8 +396,14 @@ pub struct OperatorSlot {
     /// Max amount per transaction in token smallest units (must be > 0; u64::MAX = effectively unlimited)
     pub per_tx_limit: u64,
 
+    /// Operator may only spend accrued yield, never principal
+    pub yield_only: bool,
+
+    /// Bitmask of `OPERATOR_*` permissions granted to this operator
+    pub permissions: u8,
+
     /// Reserved for future use (daily limits, etc.)
-    pub _reserved: [u8; 24],
+    pub _reserved: [u8; 22],
 }
 
 impl Default for OperatorSlot {
@@ -81,7 +411,9 @@ impl Default for OperatorSlot {
         Self {
             pubkey: Pubkey::default(),
             per_tx_limit: 0,
-            _reserved: [0u8; 24],
+            yield_only: false,
+            permissions: 0,
+            _reserved: [0u8; 22],
         }
     }
 }
@@ -89,5 +421,11 @@ impl Default for OperatorSlot {
 impl OperatorSlot {
     pub const SPACE: usize = 32  // pubkey
         + 8                      // per_tx_limit
-        + 24;                    // _reserved
+        + 1                      // yield_only
+        + 1                      // permissions
+        + 22;                    // _reserved
+
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
 }
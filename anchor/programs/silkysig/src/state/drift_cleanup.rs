@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Left behind by `close_account` while Drift still holds the account's user, so
+/// `cleanup_drift_user` refunds whoever owned the account when it was closed
+#[account]
+pub struct DriftCleanup {
+    /// Version for future upgrades
+    pub version: u8,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Closed Silk account the Drift user belongs to (part of the PDA seeds)
+    pub account: Pubkey,
+
+    /// Owner at close — receives the Drift user's rent and this record's rent
+    pub owner: Pubkey,
+
+    /// Drift user left behind by the close
+    pub drift_user: Pubkey,
+
+    /// Reserved for future fields (avoids realloc migrations)
+    pub _reserved: [u8; 32],
+}
+
+impl DriftCleanup {
+    pub const SPACE: usize = 8  // discriminator
+        + 1                     // version
+        + 1                     // bump
+        + 32                    // account
+        + 32                    // owner
+        + 32                    // drift_user
+        + 32;                   // _reserved
+}
@@ -1,3 +1,9 @@
 mod account;
+mod drift_cleanup;
+mod pending_transfer;
+mod recurring_payment;
 
 pub use account::*;
+pub use drift_cleanup::*;
+pub use pending_transfer::*;
+pub use recurring_payment::*;
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// Over-limit operator transfer awaiting owner approval
+#[account]
+pub struct PendingTransfer {
+    /// Version for future upgrades
+    pub version: u8,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Silk account the funds will come from
+    pub account: Pubkey,
+
+    /// Sequential id within the Silk account (part of the PDA seeds)
+    pub id: u64,
+
+    /// Operator that requested the transfer — pays and reclaims rent
+    pub requester: Pubkey,
+
+    /// Recipient wallet (ATA authority)
+    pub recipient: Pubkey,
+
+    /// Requested amount in token smallest units
+    pub amount: u64,
+
+    /// Unix timestamp of the request
+    pub created_at: i64,
+
+    /// Unix timestamp after which the request can no longer be approved
+    pub expires_at: i64,
+
+    /// Reserved for future fields (avoids realloc migrations)
+    pub _reserved: [u8; 32],
+}
+
+impl PendingTransfer {
+    pub const SPACE: usize = 8  // discriminator
+        + 1                     // version
+        + 1                     // bump
+        + 32                    // account
+        + 8                     // id
+        + 32                    // requester
+        + 32                    // recipient
+        + 8                     // amount
+        + 8                     // created_at
+        + 8                     // expires_at
+        + 32;                   // _reserved
+
+    /// Whether the request can no longer be approved.
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+}
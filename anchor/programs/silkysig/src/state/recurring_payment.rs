@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+/// Owner-scheduled payment paid out by the permissionless `execute_recurring` crank
+#[account]
+pub struct RecurringPayment {
+    /// Version for future upgrades
+    pub version: u8,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Silk account the funds come from
+    pub account: Pubkey,
+
+    /// Sequential id within the Silk account (part of the PDA seeds)
+    pub id: u64,
+
+    /// Owner-set member that created the schedule — pays and reclaims rent
+    pub rent_payer: Pubkey,
+
+    /// Recipient wallet (ATA authority)
+    pub recipient: Pubkey,
+
+    /// Amount per payment in token smallest units
+    pub amount: u64,
+
+    /// Seconds between payments
+    pub interval: i64,
+
+    /// Unix timestamp the next payment becomes due
+    pub next_due: i64,
+
+    /// Unix timestamp after which no further payment is due (None = open-ended)
+    pub end_at: Option<i64>,
+
+    /// Number of payments made so far
+    pub payments_made: u64,
+
+    /// Most overdue periods the crank pays back-to-back; older missed periods are
+    /// skipped unpaid (None = every missed period is paid)
+    pub max_catch_up: Option<u16>,
+
+    /// `generation` of the Silk account when the schedule was created
+    pub account_generation: u64,
+
+    /// Reserved for future fields (avoids realloc migrations)
+    pub _reserved: [u8; 21],
+}
+
+impl RecurringPayment {
+    pub const SPACE: usize = 8  // discriminator
+        + 1                     // version
+        + 1                     // bump
+        + 32                    // account
+        + 8                     // id
+        + 32                    // rent_payer
+        + 32                    // recipient
+        + 8                     // amount
+        + 8                     // interval
+        + 8                     // next_due
+        + 9                     // end_at (Option<i64>)
+        + 8                     // payments_made
+        + 3                     // max_catch_up (Option<u16>)
+        + 8                     // account_generation
+        + 21;                   // _reserved
+
+    /// Whether the schedule has no payments left.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.end_at, Some(end_at) if self.next_due > end_at)
+    }
+
+    /// Move `next_due` past the missed periods beyond `max_catch_up` as of `now`,
+    /// returning how many periods were skipped.
+    pub fn skip_missed_periods(&mut self, now: i64) -> Result<u64> {
+        let Some(max_catch_up) = self.max_catch_up else {
+            return Ok(0);
+        };
+        // Periods after end_at were never due
+        let last_due = self.end_at.map_or(now, |end_at| end_at.min(now));
+        if last_due < self.next_due {
+            return Ok(0);
+        }
+
+        let periods_due = (last_due - self.next_due) / self.interval + 1;
+        let skipped = periods_due.saturating_sub(i64::from(max_catch_up));
+        self.next_due = skipped
+            .checked_mul(self.interval)
+            .and_then(|skip| self.next_due.checked_add(skip))
+            .ok_or(SilkysigError::MathOverflow)?;
+
+        Ok(skipped as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    fn schedule(max_catch_up: Option<u16>, end_at: Option<i64>) -> RecurringPayment {
+        RecurringPayment {
+            version: 1,
+            bump: 255,
+            account: Pubkey::new_unique(),
+            id: 0,
+            rent_payer: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            amount: 1_000,
+            interval: DAY,
+            next_due: 0,
+            end_at,
+            payments_made: 0,
+            max_catch_up,
+            account_generation: 0,
+            _reserved: [0u8; 21],
+        }
+    }
+
+    #[test]
+    fn without_a_limit_every_missed_period_stays_due() {
+        let mut payment = schedule(None, None);
+        assert_eq!(payment.skip_missed_periods(10 * DAY).unwrap(), 0);
+        assert_eq!(payment.next_due, 0);
+    }
+
+    #[test]
+    fn limit_keeps_only_the_latest_missed_periods() {
+        // Periods at 0..=10 days are due; a limit of 2 leaves days 9 and 10
+        let mut payment = schedule(Some(2), None);
+        assert_eq!(payment.skip_missed_periods(10 * DAY + 5).unwrap(), 9);
+        assert_eq!(payment.next_due, 9 * DAY);
+
+        // Nothing more to skip until the crank has paid those
+        assert_eq!(payment.skip_missed_periods(10 * DAY + 5).unwrap(), 0);
+        assert_eq!(payment.next_due, 9 * DAY);
+    }
+
+    #[test]
+    fn periods_after_end_are_not_counted() {
+        // Only days 0..=4 were ever due; a limit of 1 pays day 4
+        let mut payment = schedule(Some(1), Some(4 * DAY));
+        assert_eq!(payment.skip_missed_periods(30 * DAY).unwrap(), 4);
+        assert_eq!(payment.next_due, 4 * DAY);
+        assert!(!payment.is_finished());
+    }
+
+    #[test]
+    fn not_yet_due_skips_nothing() {
+        let mut payment = schedule(Some(1), None);
+        payment.next_due = 5 * DAY;
+        assert_eq!(payment.skip_missed_periods(DAY).unwrap(), 0);
+        assert_eq!(payment.next_due, 5 * DAY);
+    }
+}
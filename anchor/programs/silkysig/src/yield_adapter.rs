@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use crate::{drift::{DriftAdapter, DRIFT_ACCOUNTS_LEN}, errors::*, state::*};
+
+/// A lending venue the account's idle funds can be parked in.
+///
+/// Adapters move tokens between the account's ATA and the venue, reading any
+/// venue accounts they need from the instruction's remaining accounts. Adding a
+/// venue means a new `YieldAdapterKind`, an implementation, and a `load` arm —
+/// instructions only talk to this trait.
+pub trait YieldAdapter<'info> {
+    /// Move `amount` from the account ATA into the venue.
+    fn deposit(&self, amount: u64) -> Result<()>;
+
+    /// Move `amount` from the venue back into the account ATA.
+    fn withdraw(&self, amount: u64) -> Result<()>;
+
+    /// Move the whole position back into the account ATA.
+    fn withdraw_all(&self) -> Result<()>;
+
+    /// Token value of the position currently held in the venue.
+    fn position_value(&self) -> Result<u64>;
+}
+
+/// Accounts every adapter is built from.
+pub struct AdapterContext<'a, 'info> {
+    pub silk_account: &'a Account<'info, SilkAccount>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    pub account_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Build the adapter for the account's configured venue (None when yield is off).
+pub fn load<'a, 'info: 'a>(
+    ctx: AdapterContext<'a, 'info>,
+) -> Result<Option<Box<dyn YieldAdapter<'info> + 'a>>> {
+    match ctx.silk_account.yield_adapter {
+        YieldAdapterKind::None => Ok(None),
+        YieldAdapterKind::Drift => Ok(Some(Box::new(DriftAdapter::new(ctx)?))),
+    }
+}
+
+/// Number of venue accounts an adapter of `kind` reads from the start of remaining accounts.
+pub fn accounts_len(kind: YieldAdapterKind) -> usize {
+    match kind {
+        YieldAdapterKind::None => 0,
+        YieldAdapterKind::Drift => DRIFT_ACCOUNTS_LEN,
+    }
+}
+
+/// Total value of the account: the adapter's position (if any) plus the ATA balance.
+pub fn account_value(adapter: Option<&dyn YieldAdapter>, ata_amount: u64) -> Result<u64> {
+    let position_value = match adapter {
+        Some(adapter) => adapter.position_value()?,
+        None => 0,
+    };
+
+    position_value
+        .checked_add(ata_amount)
+        .ok_or(SilkysigError::MathOverflow.into())
+}
+
+/// Tokens `settle_buffer` moved between the ATA and the venue.
+pub struct BufferSettlement {
+    pub buffer_target: u64,
+    pub deposited: u64,
+    pub withdrawn: u64,
+}
+
+/// Move funds so the ATA holds exactly the buffer target for the account's total value.
+/// Withdrawals are capped at the position, so an underfunded buffer takes what exists.
+pub fn settle_buffer(
+    adapter: &dyn YieldAdapter,
+    buffer_balance: u64,
+    buffer_target: impl FnOnce(u64) -> u64,
+) -> Result<BufferSettlement> {
+    let position_value = adapter.position_value()?;
+    let total_value = position_value
+        .checked_add(buffer_balance)
+        .ok_or(SilkysigError::MathOverflow)?;
+    let buffer_target = buffer_target(total_value);
+
+    let mut deposited = 0;
+    let mut withdrawn = 0;
+    if buffer_balance > buffer_target {
+        deposited = buffer_balance - buffer_target;
+        adapter.deposit(deposited)?;
+    } else if buffer_balance < buffer_target {
+        withdrawn = (buffer_target - buffer_balance).min(position_value);
+        if withdrawn > 0 {
+            adapter.withdraw(withdrawn)?;
+        }
+    }
+
+    Ok(BufferSettlement {
+        buffer_target,
+        deposited,
+        withdrawn,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// In-memory venue recording every call it receives
+    #[derive(Default)]
+    struct MockAdapter {
+        position: RefCell<u64>,
+        calls: RefCell<Vec<(&'static str, u64)>>,
+    }
+
+    impl MockAdapter {
+        fn with_position(position: u64) -> Self {
+            Self {
+                position: RefCell::new(position),
+                ..Default::default()
+            }
+        }
+    }
+
+    impl<'info> YieldAdapter<'info> for MockAdapter {
+        fn deposit(&self, amount: u64) -> Result<()> {
+            *self.position.borrow_mut() += amount;
+            self.calls.borrow_mut().push(("deposit", amount));
+            Ok(())
+        }
+
+        fn withdraw(&self, amount: u64) -> Result<()> {
+            let mut position = self.position.borrow_mut();
+            *position = position.checked_sub(amount).ok_or(SilkysigError::MathOverflow)?;
+            self.calls.borrow_mut().push(("withdraw", amount));
+            Ok(())
+        }
+
+        fn withdraw_all(&self) -> Result<()> {
+            let amount = self.position.replace(0);
+            self.calls.borrow_mut().push(("withdraw_all", amount));
+            Ok(())
+        }
+
+        fn position_value(&self) -> Result<u64> {
+            Ok(*self.position.borrow())
+        }
+    }
+
+    #[test]
+    fn account_value_adds_position_to_ata() {
+        let adapter = MockAdapter::with_position(700);
+        assert_eq!(account_value(Some(&adapter), 300).unwrap(), 1_000);
+        assert_eq!(account_value(None, 300).unwrap(), 300);
+    }
+
+    #[test]
+    fn account_value_rejects_overflow() {
+        let adapter = MockAdapter::with_position(u64::MAX);
+        assert!(account_value(Some(&adapter), 1).is_err());
+    }
+
+    #[test]
+    fn settle_buffer_deposits_excess_over_target() {
+        let adapter = MockAdapter::with_position(600);
+        // 10% of 1_000 total value
+        let settled = settle_buffer(&adapter, 400, |total| total / 10).unwrap();
+
+        assert_eq!(settled.buffer_target, 100);
+        assert_eq!((settled.deposited, settled.withdrawn), (300, 0));
+        assert_eq!(*adapter.calls.borrow(), vec![("deposit", 300)]);
+        assert_eq!(adapter.position_value().unwrap(), 900);
+    }
+
+    #[test]
+    fn settle_buffer_withdraws_shortfall_capped_at_position() {
+        let adapter = MockAdapter::with_position(50);
+        let settled = settle_buffer(&adapter, 20, |_| 500).unwrap();
+
+        assert_eq!((settled.deposited, settled.withdrawn), (0, 50));
+        assert_eq!(*adapter.calls.borrow(), vec![("withdraw", 50)]);
+        assert_eq!(adapter.position_value().unwrap(), 0);
+    }
+
+    #[test]
+    fn settle_buffer_leaves_balanced_account_alone() {
+        let adapter = MockAdapter::with_position(900);
+        let settled = settle_buffer(&adapter, 100, |total| total / 10).unwrap();
+
+        assert_eq!((settled.deposited, settled.withdrawn), (0, 0));
+        assert!(adapter.calls.borrow().is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+declare_id!("8fCLCPwHir62199RaHCNxxUrQGimiycPPav1XWi3bVqM");
+
+/// Counter PDA seed (`["counter", mint]`)
+pub const COUNTER_SEED: &[u8] = b"counter";
+
+/// Test-only transfer hook: every transfer of a mint bumps that mint's counter.
+///
+/// The counter is the hook's one extra account, so a transfer only succeeds when
+/// the caller forwards it — which is what the programs' tests check.
+#[program]
+pub mod transfer_hook_counter {
+    use super::*;
+
+    /// Create the mint's extra-account list (the counter PDA) and the counter itself
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        let extra_account_metas = extra_account_metas()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+        Ok(())
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+        let counter = &mut ctx.accounts.counter;
+        counter.transfers = counter.transfers.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    // Execute accounts: source, mint, destination, authority, extra_account_meta_list
+    Ok(vec![ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: COUNTER_SEED.to_vec(),
+            },
+            Seed::AccountKey { index: 1 },
+        ],
+        false,
+        true,
+    )?])
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Validation account Token-2022 reads the extra accounts from
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: Mint the hook is installed on
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TransferCounter::INIT_SPACE,
+        seeds = [COUNTER_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub counter: Account<'info, TransferCounter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    /// CHECK: Source token account
+    pub source_token: UncheckedAccount<'info>,
+    /// CHECK: Mint
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: Destination token account
+    pub destination_token: UncheckedAccount<'info>,
+    /// CHECK: Source owner or delegate
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: Validation account
+    #[account(seeds = [b"extra-account-metas", mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    #[account(mut, seeds = [COUNTER_SEED, mint.key().as_ref()], bump)]
+    pub counter: Account<'info, TransferCounter>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TransferCounter {
+    pub transfers: u64,
+}
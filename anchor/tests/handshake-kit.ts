@@ -283,6 +283,9 @@ function createTransferAccounts(
     tokenProgram: toPubkey(TOKEN_PROGRAM_ADDRESS),
     systemProgram: toPubkey(SYSTEM_PROGRAM_ADDRESS),
     associatedTokenProgram: toPubkey(ASSOCIATED_TOKEN_PROGRAM_ADDRESS),
+    refundTo: null,
+    rentTo: null,
+    poolMint: null,
   };
 }
 
@@ -299,9 +302,11 @@ function cancelTransferAccounts(
     pool: toPubkey(poolPda),
     mint: toPubkey(mint),
     poolTokenAccount: toPubkey(poolAta),
-    senderTokenAccount: toPubkey(senderAta),
+    refundTo: toPubkey(senderAta),
     transfer: toPubkey(transferPda),
+    rentTo: toPubkey(sender),
     tokenProgram: toPubkey(TOKEN_PROGRAM_ADDRESS),
+    poolMint: null,
   };
 }
 
@@ -321,8 +326,9 @@ function claimTransferAccounts(
     poolTokenAccount: toPubkey(poolAta),
     recipientTokenAccount: toPubkey(recipientAta),
     transfer: toPubkey(transferPda),
-    sender: toPubkey(sender),
+    rentTo: toPubkey(sender),
     tokenProgram: toPubkey(TOKEN_PROGRAM_ADDRESS),
+    poolMint: null,
   };
 }
 
@@ -340,10 +346,11 @@ function rejectTransferAccounts(
     pool: toPubkey(poolPda),
     mint: toPubkey(mint),
     poolTokenAccount: toPubkey(poolAta),
-    senderTokenAccount: toPubkey(senderAta),
+    refundTo: toPubkey(senderAta),
     transfer: toPubkey(transferPda),
-    sender: toPubkey(sender),
+    rentTo: toPubkey(sender),
     tokenProgram: toPubkey(TOKEN_PROGRAM_ADDRESS),
+    poolMint: null,
   };
 }
 
@@ -361,10 +368,11 @@ function declineTransferAccounts(
     pool: toPubkey(poolPda),
     mint: toPubkey(mint),
     poolTokenAccount: toPubkey(poolAta),
-    senderTokenAccount: toPubkey(senderAta),
+    refundTo: toPubkey(senderAta),
     transfer: toPubkey(transferPda),
-    sender: toPubkey(sender),
+    rentTo: toPubkey(sender),
     tokenProgram: toPubkey(TOKEN_PROGRAM_ADDRESS),
+    poolMint: null,
   };
 }
 
@@ -382,10 +390,11 @@ function expireTransferAccounts(
     pool: toPubkey(poolPda),
     mint: toPubkey(mint),
     poolTokenAccount: toPubkey(poolAta),
-    senderTokenAccount: toPubkey(senderAta),
+    refundTo: toPubkey(senderAta),
     transfer: toPubkey(transferPda),
-    sender: toPubkey(sender),
+    rentTo: toPubkey(sender),
     tokenProgram: toPubkey(TOKEN_PROGRAM_ADDRESS),
+    poolMint: null,
   };
 }
 
@@ -405,6 +414,7 @@ function destroyTransferAccounts(
     operatorTokenAccount: toPubkey(operatorAta),
     transfer: toPubkey(transferPda),
     tokenProgram: toPubkey(TOKEN_PROGRAM_ADDRESS),
+    poolMint: null,
   };
 }
 
@@ -1462,7 +1472,7 @@ describe("handshake (kit)", () => {
 
       try {
         await program.methods
-          .closePool(new BN(0))
+          .closePool()
           .accounts({
             operator: toPubkey(operator),
             pool: toPubkey(zeroFeePoolPda),
@@ -1490,7 +1500,7 @@ describe("handshake (kit)", () => {
       const operatorBalBefore = await getTokenBalance(operatorAta);
 
       await program.methods
-        .closePool(new BN(poolTokenBal.toString()))
+        .closePool()
         .accounts({
           operator: toPubkey(operator),
           pool: toPubkey(zeroFeePoolPda),
@@ -2,11 +2,26 @@ import * as anchor from "@coral-xyz/anchor";
 import { BN, Program, web3 } from "@coral-xyz/anchor";
 import {
   createMint,
+  createAccount as createTokenAccount,
   createAssociatedTokenAccount,
   mintTo,
+  closeAccount,
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  NATIVE_MINT,
+  ExtensionType,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createInitializeNonTransferableMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferHookInstruction,
+  createUpdateTransferHookInstruction,
+  getExtraAccountMetaAddress,
+  getMint,
+  getTransferFeeConfig,
 } from "@solana/spl-token";
 import {
   PublicKey,
@@ -17,6 +32,7 @@ import {
 } from "@solana/web3.js";
 import { assert } from "chai";
 import { Handshake } from "../target/types/handshake";
+import { TransferHookCounter } from "../target/types/transfer_hook_counter";
 
 // PDA seed constants (must match on-chain constants)
 const POOL_SEED = Buffer.from("pool");
@@ -55,8 +71,12 @@ function findTransferPda(
   );
 }
 
-function getAta(mint: PublicKey, owner: PublicKey): PublicKey {
-  return getAssociatedTokenAddressSync(mint, owner, true);
+function getAta(
+  mint: PublicKey,
+  owner: PublicKey,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): PublicKey {
+  return getAssociatedTokenAddressSync(mint, owner, true, tokenProgram);
 }
 
 async function getTokenBalance(
@@ -84,6 +104,9 @@ function createTransferAccounts(
     tokenProgram: TOKEN_PROGRAM_ID,
     systemProgram: SystemProgram.programId,
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    refundTo: null,
+    rentTo: null,
+    poolMint: null,
   };
 }
 
@@ -99,9 +122,11 @@ function cancelTransferAccounts(
     pool: poolPda,
     mint,
     poolTokenAccount: getAta(mint, poolPda),
-    senderTokenAccount: getAta(mint, sender),
+    refundTo: getAta(mint, sender),
     transfer: transferPda,
+    rentTo: sender,
     tokenProgram: TOKEN_PROGRAM_ID,
+    poolMint: null,
   };
 }
 
@@ -120,8 +145,9 @@ function claimTransferAccounts(
     poolTokenAccount: getAta(mint, poolPda),
     recipientTokenAccount: getAta(mint, recipient),
     transfer: transferPda,
-    sender,
+    rentTo: sender,
     tokenProgram: TOKEN_PROGRAM_ID,
+    poolMint: null,
   };
 }
 
@@ -138,10 +164,11 @@ function rejectTransferAccounts(
     pool: poolPda,
     mint,
     poolTokenAccount: getAta(mint, poolPda),
-    senderTokenAccount: getAta(mint, sender),
+    refundTo: getAta(mint, sender),
     transfer: transferPda,
-    sender,
+    rentTo: sender,
     tokenProgram: TOKEN_PROGRAM_ID,
+    poolMint: null,
   };
 }
 
@@ -158,10 +185,11 @@ function declineTransferAccounts(
     pool: poolPda,
     mint,
     poolTokenAccount: getAta(mint, poolPda),
-    senderTokenAccount: getAta(mint, sender),
+    refundTo: getAta(mint, sender),
     transfer: transferPda,
-    sender,
+    rentTo: sender,
     tokenProgram: TOKEN_PROGRAM_ID,
+    poolMint: null,
   };
 }
 
@@ -178,10 +206,11 @@ function expireTransferAccounts(
     pool: poolPda,
     mint,
     poolTokenAccount: getAta(mint, poolPda),
-    senderTokenAccount: getAta(mint, sender),
+    refundTo: getAta(mint, sender),
     transfer: transferPda,
-    sender,
+    rentTo: sender,
     tokenProgram: TOKEN_PROGRAM_ID,
+    poolMint: null,
   };
 }
 
@@ -198,10 +227,11 @@ function destroyTransferAccounts(
     pool: poolPda,
     mint,
     poolTokenAccount: getAta(mint, poolPda),
-    senderTokenAccount: getAta(mint, sender),
-    sender,
+    refundTo: getAta(mint, sender),
+    rentTo: sender,
     transfer: transferPda,
     tokenProgram: TOKEN_PROGRAM_ID,
+    poolMint: null,
   };
 }
 
@@ -219,6 +249,7 @@ describe("handshake", () => {
 
   const program = anchor.workspace.handshake as Program<Handshake>;
   const programId = program.programId;
+  const hookProgram = anchor.workspace.transferHookCounter as Program<TransferHookCounter>;
   const { connection } = provider;
   const operator = provider.wallet.publicKey;
   const payerKeypair = (provider.wallet as any).payer as Keypair;
@@ -1309,7 +1340,7 @@ describe("handshake", () => {
 
       try {
         await program.methods
-          .closePool(new BN(0))
+          .closePool()
           .accounts({
             operator,
             pool: zeroFeePoolPda,
@@ -1338,7 +1369,7 @@ describe("handshake", () => {
       const operatorBalBefore = await getTokenBalance(connection, getAta(mint, operator));
 
       await program.methods
-        .closePool(poolTokenBal)
+        .closePool()
         .accounts({
           operator,
           pool: zeroFeePoolPda,
@@ -1363,4 +1394,966 @@ describe("handshake", () => {
       }
     });
   });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group J: Non-ATA Source Accounts
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("J. Non-ATA Source Accounts", () => {
+    const TRANSFER_AMOUNT = new BN(100 * 1_000_000);
+
+    it("J1. refunds go to the token account the transfer was funded from", async () => {
+      const source = await createTokenAccount(
+        connection,
+        payerKeypair,
+        mint,
+        sender.publicKey,
+        Keypair.generate()
+      );
+      await mintTo(connection, payerKeypair, mint, source, payerKeypair, TRANSFER_AMOUNT.toNumber());
+
+      const nonce = nextNonce();
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, TRANSFER_AMOUNT, "non-ata", new BN(0), new BN(0))
+        .accounts({
+          ...createTransferAccounts(sender.publicKey, feePoolPda, mint, transferPda),
+          senderTokenAccount: source,
+        })
+        .signers([sender])
+        .rpc();
+
+      const transfer = await program.account.secureTransfer.fetch(transferPda);
+      assert.equal(transfer.refundTo.toString(), source.toString());
+      assert.equal((await getTokenBalance(connection, source)).toNumber(), 0);
+
+      // The sender's ATA is not the recorded refund destination
+      try {
+        await program.methods
+          .rejectTransfer(null)
+          .accounts(rejectTransferAccounts(operator, sender.publicKey, feePoolPda, mint, transferPda))
+          .rpc();
+        assert.fail("Should have failed — refund to a different token account");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidRefundAccount");
+      }
+
+      await program.methods
+        .rejectTransfer(null)
+        .accounts({
+          ...rejectTransferAccounts(operator, sender.publicKey, feePoolPda, mint, transferPda),
+          refundTo: source,
+        })
+        .rpc();
+
+      assert.equal(
+        (await getTokenBalance(connection, source)).toString(),
+        TRANSFER_AMOUNT.toString()
+      );
+    });
+
+    it("J2. refund and rent can be redirected at creation (e.g. to cold storage)", async () => {
+      const cold = Keypair.generate();
+      const coldAta = await createAssociatedTokenAccount(connection, payerKeypair, mint, cold.publicKey);
+
+      const nonce = nextNonce();
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, TRANSFER_AMOUNT, "cold refund", new BN(0), new BN(0))
+        .accounts({
+          ...createTransferAccounts(sender.publicKey, feePoolPda, mint, transferPda),
+          refundTo: coldAta,
+          rentTo: cold.publicKey,
+        })
+        .signers([sender])
+        .rpc();
+
+      const transfer = await program.account.secureTransfer.fetch(transferPda);
+      assert.equal(transfer.refundTo.toString(), coldAta.toString());
+      assert.equal(transfer.rentTo.toString(), cold.publicKey.toString());
+
+      // Rent must go to the recorded rent recipient, not the sender
+      try {
+        await program.methods
+          .cancelTransfer()
+          .accounts({
+            ...cancelTransferAccounts(sender.publicKey, feePoolPda, mint, transferPda),
+            refundTo: coldAta,
+          })
+          .signers([sender])
+          .rpc();
+        assert.fail("Should have failed — rent to the sender");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidRentRecipient");
+      }
+
+      const rent = await connection.getBalance(transferPda);
+      await program.methods
+        .cancelTransfer()
+        .accounts({
+          ...cancelTransferAccounts(sender.publicKey, feePoolPda, mint, transferPda),
+          refundTo: coldAta,
+          rentTo: cold.publicKey,
+        })
+        .signers([sender])
+        .rpc();
+
+      assert.equal((await getTokenBalance(connection, coldAta)).toString(), TRANSFER_AMOUNT.toString());
+      assert.equal(await connection.getBalance(cold.publicKey), rent);
+    });
+
+    it("J3. sender re-points the refund after the recorded account is closed", async () => {
+      const old = await createTokenAccount(
+        connection,
+        payerKeypair,
+        mint,
+        sender.publicKey,
+        Keypair.generate()
+      );
+      const cold = Keypair.generate();
+      const coldAta = await createAssociatedTokenAccount(connection, payerKeypair, mint, cold.publicKey);
+
+      const nonce = nextNonce();
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, TRANSFER_AMOUNT, "re-point", new BN(0), new BN(0))
+        .accounts({
+          ...createTransferAccounts(sender.publicKey, feePoolPda, mint, transferPda),
+          refundTo: old,
+        })
+        .signers([sender])
+        .rpc();
+      await closeAccount(connection, payerKeypair, old, sender.publicKey, sender);
+
+      const updateAccounts = {
+        sender: sender.publicKey,
+        pool: feePoolPda,
+        mint,
+        transfer: transferPda,
+        refundTo: coldAta,
+        rentTo: cold.publicKey,
+        poolMint: null,
+      };
+
+      // Only the sender may re-point
+      try {
+        await program.methods
+          .updateRefundTo()
+          .accounts({ ...updateAccounts, sender: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+        assert.fail("Should have failed — recipient re-pointing the refund");
+      } catch (err: any) {
+        assert.include(err.toString(), "OnlySenderCanCancel");
+      }
+
+      await program.methods
+        .updateRefundTo()
+        .accounts(updateAccounts)
+        .signers([sender])
+        .rpc();
+      const transfer = await program.account.secureTransfer.fetch(transferPda);
+      assert.equal(transfer.refundTo.toString(), coldAta.toString());
+      assert.equal(transfer.rentTo.toString(), cold.publicKey.toString());
+
+      // Permissionless refund paths now pay the new destination
+      await program.methods
+        .rejectTransfer(null)
+        .accounts({
+          ...rejectTransferAccounts(operator, sender.publicKey, feePoolPda, mint, transferPda),
+          refundTo: coldAta,
+          rentTo: cold.publicKey,
+        })
+        .rpc();
+      assert.equal((await getTokenBalance(connection, coldAta)).toString(), TRANSFER_AMOUNT.toString());
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group K: Token-2022 Mints
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("K. Token-2022 Mints", () => {
+    const FEE_MINT_BPS = 100; // 1% Token-2022 transfer fee
+
+    /** Create a Token-2022 mint (6 decimals) with the given extensions initialized */
+    async function createToken2022Mint(
+      extensions: ExtensionType[],
+      initExtensions: (mint: PublicKey) => web3.TransactionInstruction[]
+    ): Promise<PublicKey> {
+      const mintKeypair = Keypair.generate();
+      const space = getMintLen(extensions);
+      const tx = new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: operator,
+          newAccountPubkey: mintKeypair.publicKey,
+          space,
+          lamports: await connection.getMinimumBalanceForRentExemption(space),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        ...initExtensions(mintKeypair.publicKey),
+        createInitializeMintInstruction(
+          mintKeypair.publicKey,
+          6,
+          operator,
+          null,
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+      await provider.sendAndConfirm(tx, [mintKeypair]);
+      return mintKeypair.publicKey;
+    }
+
+    function initPoolAccounts(mint: PublicKey, poolPda: PublicKey) {
+      return {
+        operator,
+        mint,
+        pool: poolPda,
+        poolTokenAccount: getAta(mint, poolPda, TOKEN_2022_PROGRAM_ID),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      };
+    }
+
+    it("K1. escrows what the pool receives from a transfer-fee mint", async () => {
+      const feeMint = await createToken2022Mint([ExtensionType.TransferFeeConfig], (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          operator,
+          operator,
+          FEE_MINT_BPS,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ]);
+
+      const poolId = Keypair.generate().publicKey;
+      const [poolPda] = findPoolPda(programId, poolId);
+      await program.methods.initPool(poolId, 0).accounts(initPoolAccounts(feeMint, poolPda)).rpc();
+
+      const senderAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        feeMint,
+        sender.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(connection, payerKeypair, feeMint, senderAta, payerKeypair, 10_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+      const amount = new BN(1_000_000);
+      const expectedFee = amount.muln(FEE_MINT_BPS).divn(10_000);
+      const nonce = nextNonce();
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, amount, "fee mint", new BN(0), new BN(0))
+        .accounts({
+          ...createTransferAccounts(sender.publicKey, poolPda, feeMint, transferPda),
+          poolTokenAccount: getAta(feeMint, poolPda, TOKEN_2022_PROGRAM_ID),
+          senderTokenAccount: senderAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([sender])
+        .rpc();
+
+      const transfer = await program.account.secureTransfer.fetch(transferPda);
+      assert.equal(transfer.amount.toString(), amount.sub(expectedFee).toString());
+
+      const pool = await program.account.pool.fetch(poolPda);
+      assert.equal(pool.totalEscrowed.toString(), amount.sub(expectedFee).toString());
+    });
+
+    it("K2. rejects a pool for a non-transferable mint", async () => {
+      const soulboundMint = await createToken2022Mint([ExtensionType.NonTransferable], (mint) => [
+        createInitializeNonTransferableMintInstruction(mint, TOKEN_2022_PROGRAM_ID),
+      ]);
+
+      const poolId = Keypair.generate().publicKey;
+      const [poolPda] = findPoolPda(programId, poolId);
+      try {
+        await program.methods.initPool(poolId, 0).accounts(initPoolAccounts(soulboundMint, poolPda)).rpc();
+        assert.fail("Should have failed — non-transferable mint");
+      } catch (err: any) {
+        assert.include(err.toString(), "UnsupportedMintExtension");
+      }
+    });
+
+    it("K3. rejects a pool for a mint with a transfer hook program set", async () => {
+      const hookedMint = await createToken2022Mint([ExtensionType.TransferHook], (mint) => [
+        createInitializeTransferHookInstruction(mint, operator, hookProgram.programId, TOKEN_2022_PROGRAM_ID),
+      ]);
+
+      const poolId = Keypair.generate().publicKey;
+      const [poolPda] = findPoolPda(programId, poolId);
+      try {
+        await program.methods.initPool(poolId, 0).accounts(initPoolAccounts(hookedMint, poolPda)).rpc();
+        assert.fail("Should have failed — hook program set");
+      } catch (err: any) {
+        assert.include(err.toString(), "UnsupportedMintExtension");
+      }
+    });
+
+    it("K4. rejects a pool for a permanent-delegate mint", async () => {
+      const delegateMint = await createToken2022Mint([ExtensionType.PermanentDelegate], (mint) => [
+        createInitializePermanentDelegateInstruction(mint, operator, TOKEN_2022_PROGRAM_ID),
+      ]);
+
+      const poolId = Keypair.generate().publicKey;
+      const [poolPda] = findPoolPda(programId, poolId);
+      try {
+        await program.methods.initPool(poolId, 0).accounts(initPoolAccounts(delegateMint, poolPda)).rpc();
+        assert.fail("Should have failed — permanent delegate");
+      } catch (err: any) {
+        assert.include(err.toString(), "UnsupportedMintExtension");
+      }
+    });
+
+    it("K5. a hook set after escrow blocks new transfers but refunds forward its accounts", async () => {
+      // Hook extension with no program yet, so the pool accepts the mint
+      const hookMint = await createToken2022Mint([ExtensionType.TransferHook], (mint) => [
+        createInitializeTransferHookInstruction(mint, operator, PublicKey.default, TOKEN_2022_PROGRAM_ID),
+      ]);
+      const poolId = Keypair.generate().publicKey;
+      const [poolPda] = findPoolPda(programId, poolId);
+      await program.methods.initPool(poolId, 0).accounts(initPoolAccounts(hookMint, poolPda)).rpc();
+
+      const senderAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        hookMint,
+        sender.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(connection, payerKeypair, hookMint, senderAta, payerKeypair, 10_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+      const amount = new BN(1_000_000);
+      const hookTransferAccounts = (transferPda: PublicKey) => ({
+        ...createTransferAccounts(sender.publicKey, poolPda, hookMint, transferPda),
+        poolTokenAccount: getAta(hookMint, poolPda, TOKEN_2022_PROGRAM_ID),
+        senderTokenAccount: senderAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      });
+      const nonce = nextNonce();
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, amount, "hook later", new BN(0), new BN(0))
+        .accounts(hookTransferAccounts(transferPda))
+        .signers([sender])
+        .rpc();
+
+      // The mint authority installs the counting hook on the escrowed mint
+      const extraAccountMetas = getExtraAccountMetaAddress(hookMint, hookProgram.programId);
+      const [counter] = PublicKey.findProgramAddressSync(
+        [Buffer.from("counter"), hookMint.toBuffer()],
+        hookProgram.programId
+      );
+      await hookProgram.methods
+        .initializeExtraAccountMetaList()
+        .accounts({
+          payer: operator,
+          extraAccountMetaList: extraAccountMetas,
+          mint: hookMint,
+          counter,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          createUpdateTransferHookInstruction(hookMint, operator, hookProgram.programId, [], TOKEN_2022_PROGRAM_ID)
+        )
+      );
+
+      const laterNonce = nextNonce();
+      const [laterPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, laterNonce);
+      try {
+        await program.methods
+          .createTransfer(recipient.publicKey, laterNonce, amount, "hooked", new BN(0), new BN(0))
+          .accounts(hookTransferAccounts(laterPda))
+          .signers([sender])
+          .rpc();
+        assert.fail("Should have failed — hook program set since the pool was created");
+      } catch (err: any) {
+        assert.include(err.toString(), "UnsupportedMintExtension");
+      }
+
+      // The escrowed funds still come back, with the hook's accounts forwarded
+      await program.methods
+        .cancelTransfer()
+        .accounts({
+          ...cancelTransferAccounts(sender.publicKey, poolPda, hookMint, transferPda),
+          poolTokenAccount: getAta(hookMint, poolPda, TOKEN_2022_PROGRAM_ID),
+          refundTo: senderAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: hookProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: extraAccountMetas, isSigner: false, isWritable: false },
+          { pubkey: counter, isSigner: false, isWritable: true },
+        ])
+        .signers([sender])
+        .rpc();
+
+      assert.equal(
+        (await getTokenBalance(connection, senderAta)).toString(),
+        "10000000"
+      );
+      assert.equal((await hookProgram.account.transferCounter.fetch(counter)).transfers.toNumber(), 1);
+    });
+
+    it("K6. removing a transfer-fee child harvests the vault's withheld fees to the mint", async () => {
+      const feeMint = await createToken2022Mint([ExtensionType.TransferFeeConfig], (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          operator,
+          operator,
+          FEE_MINT_BPS,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ]);
+
+      const poolId = Keypair.generate().publicKey;
+      const [poolPda] = findPoolPda(programId, poolId);
+      await program.methods
+        .initPool(poolId, 0)
+        .accounts({
+          ...initPoolAccounts(mint, poolPda),
+          poolTokenAccount: getAta(mint, poolPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      const [poolMintPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool_mint"), poolPda.toBuffer(), feeMint.toBuffer()],
+        programId
+      );
+      const vault = getAta(feeMint, poolPda, TOKEN_2022_PROGRAM_ID);
+      await program.methods
+        .addPoolMint(0)
+        .accounts({
+          operator,
+          pool: poolPda,
+          mint: feeMint,
+          poolMint: poolMintPda,
+          poolTokenAccount: vault,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const senderAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        feeMint,
+        sender.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const operatorAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        feeMint,
+        operator,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(connection, payerKeypair, feeMint, senderAta, payerKeypair, 10_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+      // The deposit leaves the mint's fee withheld in the vault; the cancel empties it
+      const amount = new BN(1_000_000);
+      const expectedFee = amount.muln(FEE_MINT_BPS).divn(10_000);
+      const nonce = nextNonce();
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, amount, "withheld", new BN(0), new BN(0))
+        .accounts({
+          ...createTransferAccounts(sender.publicKey, poolPda, feeMint, transferPda),
+          poolTokenAccount: vault,
+          senderTokenAccount: senderAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          poolMint: poolMintPda,
+        })
+        .signers([sender])
+        .rpc();
+      await program.methods
+        .cancelTransfer()
+        .accounts({
+          ...cancelTransferAccounts(sender.publicKey, poolPda, feeMint, transferPda),
+          poolTokenAccount: vault,
+          refundTo: senderAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          poolMint: poolMintPda,
+        })
+        .signers([sender])
+        .rpc();
+
+      await program.methods
+        .removePoolMint()
+        .accounts({
+          operator,
+          pool: poolPda,
+          poolMint: poolMintPda,
+          mint: feeMint,
+          poolTokenAccount: vault,
+          operatorTokenAccount: operatorAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      assert.isNull(await connection.getAccountInfo(vault));
+      assert.isNull(await connection.getAccountInfo(poolMintPda));
+      const feeConfig = getTransferFeeConfig(
+        await getMint(connection, feeMint, undefined, TOKEN_2022_PROGRAM_ID)
+      );
+      assert.equal(feeConfig!.withheldAmount.toString(), expectedFee.toString());
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group L: Native SOL
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("L. Native SOL", () => {
+    const SOL_AMOUNT = new BN(0.01 * web3.LAMPORTS_PER_SOL);
+    let solPoolPda: PublicKey;
+
+    function findUnwrapPda(transfer: PublicKey): PublicKey {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("unwrap"), transfer.toBuffer()],
+        programId
+      )[0];
+    }
+
+    before(async () => {
+      const poolId = Keypair.generate().publicKey;
+      [solPoolPda] = findPoolPda(programId, poolId);
+      await program.methods
+        .initPool(poolId, FEE_BPS)
+        .accounts({
+          operator,
+          mint: NATIVE_MINT,
+          pool: solPoolPda,
+          poolTokenAccount: getAta(NATIVE_MINT, solPoolPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+    });
+
+    async function createNativeTransfer(
+      poolPda: PublicKey,
+      poolMint: PublicKey,
+      nonce: BN,
+      claimableUntil = new BN(0)
+    ) {
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createNativeTransfer(recipient.publicKey, nonce, SOL_AMOUNT, "sol", new BN(0), claimableUntil)
+        .accounts({
+          sender: sender.publicKey,
+          pool: poolPda,
+          poolTokenAccount: getAta(poolMint, poolPda),
+          transfer: transferPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          refundTo: null,
+          rentTo: null,
+        })
+        .signers([sender])
+        .rpc();
+      return transferPda;
+    }
+
+    it("L1. escrows lamports and pays the recipient in lamports, net of the pool fee", async () => {
+      const nonce = nextNonce();
+      const transferPda = await createNativeTransfer(solPoolPda, NATIVE_MINT, nonce);
+
+      const transfer = await program.account.secureTransfer.fetch(transferPda);
+      assert.equal(transfer.amount.toString(), SOL_AMOUNT.toString());
+      assert.equal(
+        transfer.refundTo.toString(),
+        getAta(NATIVE_MINT, sender.publicKey).toString()
+      );
+
+      let pool = await program.account.pool.fetch(solPoolPda);
+      assert.equal(pool.totalEscrowed.toString(), SOL_AMOUNT.toString());
+      assert.equal(
+        (await getTokenBalance(connection, getAta(NATIVE_MINT, solPoolPda))).toString(),
+        SOL_AMOUNT.toString()
+      );
+
+      const before = await connection.getBalance(recipient.publicKey);
+      await program.methods
+        .claimNativeTransfer()
+        .accounts({
+          recipient: recipient.publicKey,
+          pool: solPoolPda,
+          mint: NATIVE_MINT,
+          poolTokenAccount: getAta(NATIVE_MINT, solPoolPda),
+          unwrapAccount: findUnwrapPda(transferPda),
+          transfer: transferPda,
+          rentTo: sender.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([recipient])
+        .rpc();
+
+      const fee = SOL_AMOUNT.muln(FEE_BPS).divn(10_000);
+      const after = await connection.getBalance(recipient.publicKey);
+      assert.equal(after - before, SOL_AMOUNT.sub(fee).toNumber());
+      assert.isNull(await connection.getAccountInfo(findUnwrapPda(transferPda)));
+
+      pool = await program.account.pool.fetch(solPoolPda);
+      assert.equal(pool.totalEscrowed.toNumber(), 0);
+      assert.equal(pool.collectedFees.toString(), fee.toString());
+    });
+
+    it("L2. rejects a native transfer into a token pool", async () => {
+      try {
+        await createNativeTransfer(feePoolPda, mint, nextNonce());
+        assert.fail("Should have failed — not a native pool");
+      } catch (err: any) {
+        assert.include(err.toString(), "NotNativePool");
+      }
+    });
+
+    // Accounts shared by the native refund instructions; `payer` fronts the unwrap rent
+    function nativeRefundAccounts(payer: PublicKey, transferPda: PublicKey) {
+      return {
+        payer,
+        sender: sender.publicKey,
+        pool: solPoolPda,
+        mint: NATIVE_MINT,
+        poolTokenAccount: getAta(NATIVE_MINT, solPoolPda),
+        unwrapAccount: findUnwrapPda(transferPda),
+        transfer: transferPda,
+        rentTo: sender.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+    }
+
+    it("L3. sender cancels a native transfer and is refunded in lamports", async () => {
+      const transferPda = await createNativeTransfer(solPoolPda, NATIVE_MINT, nextNonce());
+      const transferRent = (await connection.getAccountInfo(transferPda))!.lamports;
+
+      const before = await connection.getBalance(sender.publicKey);
+      await program.methods
+        .cancelNativeTransfer()
+        .accounts({ refund: nativeRefundAccounts(sender.publicKey, transferPda) })
+        .signers([sender])
+        .rpc();
+
+      // Full amount (no fee on cancel) plus the transfer's rent; the unwrap rent comes back
+      const after = await connection.getBalance(sender.publicKey);
+      assert.equal(after - before, SOL_AMOUNT.toNumber() + transferRent);
+      assert.isNull(await connection.getAccountInfo(transferPda));
+      assert.isNull(await connection.getAccountInfo(findUnwrapPda(transferPda)));
+
+      const pool = await program.account.pool.fetch(solPoolPda);
+      assert.equal(pool.totalEscrowed.toNumber(), 0);
+    });
+
+    it("L4. anyone expires a native transfer; the sender is refunded in lamports", async () => {
+      const claimableUntil = new BN(Math.floor(Date.now() / 1000) + 3);
+      const transferPda = await createNativeTransfer(solPoolPda, NATIVE_MINT, nextNonce(), claimableUntil);
+      const transferRent = (await connection.getAccountInfo(transferPda))!.lamports;
+
+      // Wait for expiry (generous margin for validator clock lag)
+      await new Promise((resolve) => setTimeout(resolve, 6000));
+
+      const senderBefore = await connection.getBalance(sender.publicKey);
+      const callerBefore = await connection.getBalance(thirdParty.publicKey);
+      await program.methods
+        .expireNativeTransfer()
+        .accounts({ refund: nativeRefundAccounts(thirdParty.publicKey, transferPda) })
+        .signers([thirdParty])
+        .rpc();
+
+      const senderAfter = await connection.getBalance(sender.publicKey);
+      assert.equal(senderAfter - senderBefore, SOL_AMOUNT.toNumber() + transferRent);
+      // The caller fronted the unwrap rent and got it back
+      assert.equal(await connection.getBalance(thirdParty.publicKey), callerBefore);
+      assert.isNull(await connection.getAccountInfo(transferPda));
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group M: Multi-Mint Pools
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("M. Multi-Mint Pools", () => {
+    const USDT_FEE_BPS = 100; // 1%
+    let multiPoolPda: PublicKey;
+    let usdt: PublicKey;
+    let poolMintPda: PublicKey;
+
+    before(async () => {
+      const poolId = Keypair.generate().publicKey;
+      [multiPoolPda] = findPoolPda(programId, poolId);
+      await program.methods
+        .initPool(poolId, 0)
+        .accounts({
+          operator,
+          mint,
+          pool: multiPoolPda,
+          poolTokenAccount: getAta(mint, multiPoolPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      usdt = await createMint(connection, payerKeypair, payerKeypair.publicKey, null, 6);
+      for (const owner of [operator, sender.publicKey, recipient.publicKey]) {
+        await createAssociatedTokenAccount(connection, payerKeypair, usdt, owner);
+      }
+      await mintTo(connection, payerKeypair, usdt, getAta(usdt, sender.publicKey), payerKeypair, 1_000 * 1_000_000);
+
+      [poolMintPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool_mint"), multiPoolPda.toBuffer(), usdt.toBuffer()],
+        programId
+      );
+    });
+
+    it("M1. operator adds a second mint with its own fee", async () => {
+      await program.methods
+        .addPoolMint(USDT_FEE_BPS)
+        .accounts({
+          operator,
+          pool: multiPoolPda,
+          mint: usdt,
+          poolMint: poolMintPda,
+          poolTokenAccount: getAta(usdt, multiPoolPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const poolMint = await program.account.poolMint.fetch(poolMintPda);
+      assert.equal(poolMint.mint.toString(), usdt.toString());
+      assert.equal(poolMint.transferFeeBps, USDT_FEE_BPS);
+
+      const pool = await program.account.pool.fetch(multiPoolPda);
+      assert.equal(pool.mintCount, 1);
+    });
+
+    it("M2. a transfer in the added mint is booked and charged on its child", async () => {
+      const amount = new BN(100 * 1_000_000);
+      const nonce = nextNonce();
+      const [transferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, amount, "usdt", new BN(0), new BN(0))
+        .accounts({
+          ...createTransferAccounts(sender.publicKey, multiPoolPda, usdt, transferPda),
+          poolMint: poolMintPda,
+        })
+        .signers([sender])
+        .rpc();
+
+      const transfer = await program.account.secureTransfer.fetch(transferPda);
+      assert.equal(transfer.pool.toString(), poolMintPda.toString());
+
+      // Claiming against the pool's own mint must not reach the USDT vault
+      try {
+        await program.methods
+          .claimTransfer()
+          .accounts(claimTransferAccounts(recipient.publicKey, sender.publicKey, multiPoolPda, mint, transferPda))
+          .signers([recipient])
+          .rpc();
+        assert.fail("Should have failed — transfer belongs to the USDT child");
+      } catch (err: any) {
+        assert.include(err.toString(), "ConstraintRaw");
+      }
+
+      const before = await getTokenBalance(connection, getAta(usdt, recipient.publicKey));
+      await program.methods
+        .claimTransfer()
+        .accounts({
+          ...claimTransferAccounts(recipient.publicKey, sender.publicKey, multiPoolPda, usdt, transferPda),
+          poolMint: poolMintPda,
+        })
+        .signers([recipient])
+        .rpc();
+
+      const fee = amount.muln(USDT_FEE_BPS).divn(10_000);
+      const after = await getTokenBalance(connection, getAta(usdt, recipient.publicKey));
+      assert.equal(after.sub(before).toString(), amount.sub(fee).toString());
+
+      const poolMint = await program.account.poolMint.fetch(poolMintPda);
+      assert.equal(poolMint.totalEscrowed.toNumber(), 0);
+      assert.equal(poolMint.collectedFees.toString(), fee.toString());
+
+      // Counts stay on the pool; amounts in the pool's own mint are untouched
+      const pool = await program.account.pool.fetch(multiPoolPda);
+      assert.equal(pool.totalTransfersCreated.toNumber(), 1);
+      assert.equal(pool.totalTransfersResolved.toNumber(), 1);
+      assert.equal(pool.totalDeposits.toNumber(), 0);
+      assert.equal(pool.collectedFees.toNumber(), 0);
+    });
+
+    it("M3. pool closes only after its added mints are removed", async () => {
+      const closeAccounts = {
+        operator,
+        pool: multiPoolPda,
+        mint,
+        poolTokenAccount: getAta(mint, multiPoolPda),
+        operatorTokenAccount: getAta(mint, operator),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+      try {
+        await program.methods.closePool().accounts(closeAccounts).rpc();
+        assert.fail("Should have failed — USDT still added");
+      } catch (err: any) {
+        assert.include(err.toString(), "PoolHasMints");
+      }
+
+      const fees = (await program.account.poolMint.fetch(poolMintPda)).collectedFees;
+      const before = await getTokenBalance(connection, getAta(usdt, operator));
+      await program.methods
+        .removePoolMint()
+        .accounts({
+          operator,
+          pool: multiPoolPda,
+          poolMint: poolMintPda,
+          mint: usdt,
+          poolTokenAccount: getAta(usdt, multiPoolPda),
+          operatorTokenAccount: getAta(usdt, operator),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const after = await getTokenBalance(connection, getAta(usdt, operator));
+      assert.equal(after.sub(before).toString(), fees.toString());
+      assert.isNull(await connection.getAccountInfo(poolMintPda));
+
+      await program.methods.closePool().accounts(closeAccounts).rpc();
+      assert.isNull(await connection.getAccountInfo(multiPoolPda));
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group N: Reconcile Pool
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("N. Reconcile Pool", () => {
+    const ESCROWED = new BN(50 * 1_000_000);
+    const DONATION = new BN(5 * 1_000_000);
+    let reconcilePoolPda: PublicKey;
+    let reconcileTransferPda: PublicKey;
+
+    function reconcileAccounts(caller: PublicKey, withOperatorAccount: boolean) {
+      return {
+        caller,
+        pool: reconcilePoolPda,
+        mint,
+        poolTokenAccount: getAta(mint, reconcilePoolPda),
+        operatorTokenAccount: withOperatorAccount ? getAta(mint, operator) : null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        poolMint: null,
+      };
+    }
+
+    before(async () => {
+      const poolId = Keypair.generate().publicKey;
+      [reconcilePoolPda] = findPoolPda(programId, poolId);
+      await program.methods
+        .initPool(poolId, FEE_BPS)
+        .accounts({
+          operator,
+          mint,
+          pool: reconcilePoolPda,
+          poolTokenAccount: getAta(mint, reconcilePoolPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      const nonce = nextNonce();
+      [reconcileTransferPda] = findTransferPda(programId, sender.publicKey, recipient.publicKey, nonce);
+      await program.methods
+        .createTransfer(recipient.publicKey, nonce, ESCROWED, "reconcile", new BN(0), new BN(0))
+        .accounts(createTransferAccounts(sender.publicKey, reconcilePoolPda, mint, reconcileTransferPda))
+        .signers([sender])
+        .rpc();
+
+      // A direct donation the pool never booked
+      await mintTo(connection, payerKeypair, mint, getAta(mint, reconcilePoolPda), payerKeypair, DONATION.toNumber());
+    });
+
+    it("N1. anyone can reconcile, but only the operator can sweep", async () => {
+      await program.methods
+        .reconcilePool(false)
+        .accounts(reconcileAccounts(thirdParty.publicKey, false))
+        .signers([thirdParty])
+        .rpc();
+
+      try {
+        await program.methods
+          .reconcilePool(true)
+          .accounts(reconcileAccounts(thirdParty.publicKey, true))
+          .signers([thirdParty])
+          .rpc();
+        assert.fail("Should have failed — not the operator");
+      } catch (err: any) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+
+    it("N2. operator sweeps only the surplus, leaving escrow intact", async () => {
+      const before = await getTokenBalance(connection, getAta(mint, operator));
+      await program.methods
+        .reconcilePool(true)
+        .accounts(reconcileAccounts(operator, true))
+        .rpc();
+
+      const after = await getTokenBalance(connection, getAta(mint, operator));
+      assert.equal(after.sub(before).toString(), DONATION.toString());
+      assert.equal(
+        (await getTokenBalance(connection, getAta(mint, reconcilePoolPda))).toString(),
+        ESCROWED.toString()
+      );
+
+      const pool = await program.account.pool.fetch(reconcilePoolPda);
+      assert.equal(pool.totalEscrowed.toString(), ESCROWED.toString());
+    });
+
+    it("N3. closing pays the unwithdrawn fees and sweeps only the remainder", async () => {
+      const closeAccounts = {
+        operator,
+        pool: reconcilePoolPda,
+        mint,
+        poolTokenAccount: getAta(mint, reconcilePoolPda),
+        operatorTokenAccount: getAta(mint, operator),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+
+      await program.methods
+        .claimTransfer()
+        .accounts(claimTransferAccounts(recipient.publicKey, sender.publicKey, reconcilePoolPda, mint, reconcileTransferPda))
+        .signers([recipient])
+        .rpc();
+      await mintTo(connection, payerKeypair, mint, getAta(mint, reconcilePoolPda), payerKeypair, DONATION.toNumber());
+
+      const fees = (await program.account.pool.fetch(reconcilePoolPda)).collectedFees;
+      assert.equal(fees.toString(), ESCROWED.muln(FEE_BPS).divn(10_000).toString());
+
+      const before = await getTokenBalance(connection, getAta(mint, operator));
+      await program.methods.closePool().accounts(closeAccounts).rpc();
+
+      const after = await getTokenBalance(connection, getAta(mint, operator));
+      assert.equal(after.sub(before).toString(), fees.add(DONATION).toString());
+      assert.isNull(await connection.getAccountInfo(reconcilePoolPda));
+    });
+  });
 });
@@ -69,13 +69,20 @@ function getDriftSignerPDA(): PublicKey {
 
 // ─── Silkysig Helpers ───────────────────────────────────────────────────────────
 
-function findAccountPda(programId: PublicKey, owner: PublicKey): [PublicKey, number] {
+function findAccountPda(programId: PublicKey, owner: PublicKey, mint: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [ACCOUNT_SEED, owner.toBuffer()],
+    [ACCOUNT_SEED, owner.toBuffer(), mint.toBuffer()],
     programId
   );
 }
 
+function findDriftCleanupPda(programId: PublicKey, silkAccount: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("drift_cleanup"), silkAccount.toBuffer()],
+    programId
+  )[0];
+}
+
 function getAta(mint: PublicKey, owner: PublicKey): PublicKey {
   return getAssociatedTokenAddressSync(mint, owner, true);
 }
@@ -104,23 +111,9 @@ async function fetchSpotMarketOracle(connection: web3.Connection, marketIndex: n
 
 // ─── Remaining Accounts Builders ────────────────────────────────────────────────
 
-// Deposit: 7 remaining accounts
-// [drift_state, drift_user, drift_user_stats, drift_spot_market_vault, drift_program, drift_oracle, drift_spot_market]
-function buildDepositRemainingAccounts(silkAccountPda: PublicKey): web3.AccountMeta[] {
-  return [
-    { pubkey: DRIFT_STATE, isSigner: false, isWritable: true },
-    { pubkey: getDriftUserPDA(silkAccountPda, 0), isSigner: false, isWritable: true },
-    { pubkey: getDriftUserStatsPDA(silkAccountPda), isSigner: false, isWritable: true },
-    { pubkey: getDriftSpotMarketVaultPDA(USDC_MARKET_INDEX), isSigner: false, isWritable: true },
-    { pubkey: DRIFT_PROGRAM, isSigner: false, isWritable: false },
-    { pubkey: USDC_ORACLE, isSigner: false, isWritable: false },
-    { pubkey: getDriftSpotMarketPDA(USDC_MARKET_INDEX), isSigner: false, isWritable: true },
-  ];
-}
-
-// Withdraw: 8 remaining accounts
+// Drift account set shared by every yield instruction: 8 remaining accounts
 // [drift_state, drift_user, drift_user_stats, drift_spot_market_vault, drift_signer, drift_program, drift_oracle, drift_spot_market]
-function buildWithdrawRemainingAccounts(silkAccountPda: PublicKey): web3.AccountMeta[] {
+function buildDriftRemainingAccounts(silkAccountPda: PublicKey): web3.AccountMeta[] {
   return [
     { pubkey: DRIFT_STATE, isSigner: false, isWritable: true },
     { pubkey: getDriftUserPDA(silkAccountPda, 0), isSigner: false, isWritable: true },
@@ -147,6 +140,9 @@ describe("silkysig-yield (devnet)", () => {
 
   // Test owner — fresh keypair per run
   let owner: Keypair;
+  // Takes ownership before close (Y5i), so Drift rent must follow them, not the creator
+  let newOwner: Keypair;
+  let newOwnerAta: PublicKey;
   let accountPda: PublicKey;
   let accountAta: PublicKey;
   let ownerAta: PublicKey;
@@ -170,12 +166,14 @@ describe("silkysig-yield (devnet)", () => {
 
     // Create fresh owner
     owner = Keypair.generate();
+    newOwner = Keypair.generate();
     console.log(`Owner: ${owner.publicKey.toString()}`);
 
     // Derive PDAs
-    [accountPda] = findAccountPda(programId, owner.publicKey);
+    [accountPda] = findAccountPda(programId, owner.publicKey, USDC_MINT);
     accountAta = getAta(USDC_MINT, accountPda);
     ownerAta = getAta(USDC_MINT, owner.publicKey);
+    newOwnerAta = getAta(USDC_MINT, newOwner.publicKey);
     driftUser = getDriftUserPDA(accountPda, 0);
     driftUserStats = getDriftUserStatsPDA(accountPda);
 
@@ -279,7 +277,7 @@ describe("silkysig-yield (devnet)", () => {
     const ix = await program.methods
       .initDriftUser(0, stringToBytes32("silkysig-test"), USDC_MARKET_INDEX)
       .accounts({
-        owner: owner.publicKey,
+        signer: owner.publicKey,
         silkAccount: accountPda,
         mint: USDC_MINT,
         accountTokenAccount: accountAta,
@@ -329,6 +327,7 @@ describe("silkysig-yield (devnet)", () => {
     assert.isNotNull(acct.driftUser, "drift_user should be set");
     assert.deepEqual(acct.driftUser, driftUser);
     assert.equal(acct.driftMarketIndex, USDC_MARKET_INDEX);
+    assert.deepEqual(acct.yieldAdapter, { drift: {} });
     assert.equal(acct.principalBalance.toNumber(), DEPOSIT_AMOUNT);
 
     // ATA should be drained (funds moved to Drift)
@@ -351,7 +350,7 @@ describe("silkysig-yield (devnet)", () => {
         depositorTokenAccount: ownerAta,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .remainingAccounts(buildDepositRemainingAccounts(accountPda))
+      .remainingAccounts(buildDriftRemainingAccounts(accountPda))
       .signers([owner])
       .rpc({ skipPreflight: true });
 
@@ -378,8 +377,9 @@ describe("silkysig-yield (devnet)", () => {
     await provider.sendAndConfirm(fundTx);
 
     const recipientAta = getAta(USDC_MINT, recipient.publicKey);
+    const before = await program.account.silkAccount.fetch(accountPda);
 
-    await program.methods
+    const txSig = await program.methods
       .transferFromAccount(new BN(TRANSFER_AMOUNT))
       .accounts({
         signer: owner.publicKey,
@@ -392,46 +392,334 @@ describe("silkysig-yield (devnet)", () => {
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
-      .remainingAccounts(buildWithdrawRemainingAccounts(accountPda))
+      .remainingAccounts(buildDriftRemainingAccounts(accountPda))
       .signers([owner])
       .rpc({ skipPreflight: true });
 
     const recipientBal = await getTokenBalance(connection, recipientAta);
     assert.equal(recipientBal.toNumber(), TRANSFER_AMOUNT);
 
+    // The split is priced off Drift's value at execution, so read it from the event
+    await connection.confirmTransaction(txSig, "confirmed");
+    const txDetails = await connection.getTransaction(txSig, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(programId, program.coder);
+    const executed = [...parser.parseLogs(txDetails!.meta!.logMessages!)].find(
+      (e) => e.name === "transferExecuted"
+    );
+    assert.ok(executed, "TransferExecuted should be emitted");
+    const fromYield = executed!.data.fromYield.toNumber();
+    const fromPrincipal = executed!.data.fromPrincipal.toNumber();
+
+    // Yield accrued since the deposits is spent first; seconds of yield never cover 2 USDC
+    assert.equal(before.principalBalance.toNumber(), DEPOSIT_AMOUNT + 3_000_000);
+    assert.equal(fromYield + fromPrincipal, TRANSFER_AMOUNT);
+    assert.isAbove(fromPrincipal, 0);
+
     const acct = await program.account.silkAccount.fetch(accountPda);
-    const expectedPrincipal = DEPOSIT_AMOUNT + 3_000_000 - TRANSFER_AMOUNT;
-    assert.equal(acct.principalBalance.toNumber(), expectedPrincipal);
+    assert.equal(acct.principalBalance.toNumber(), DEPOSIT_AMOUNT + 3_000_000 - fromPrincipal);
+    assert.equal(acct.yieldWithdrawn.toNumber(), before.yieldWithdrawn.toNumber() + fromYield);
     console.log(`Transferred ${TRANSFER_AMOUNT / 1e6} USDC to recipient, principal: ${acct.principalBalance.toNumber() / 1e6}`);
   });
 
+  it("Y5a. sync_yield — records Drift position value and accrued yield", async () => {
+    await program.methods
+      .syncYield()
+      .accounts({
+        caller: payer,
+        silkAccount: accountPda,
+        accountTokenAccount: accountAta,
+        driftUser,
+        driftSpotMarket: getDriftSpotMarketPDA(USDC_MARKET_INDEX),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ skipPreflight: true });
+
+    const acct = await program.account.silkAccount.fetch(accountPda);
+    const principal = acct.principalBalance.toNumber();
+    assert.ok(acct.lastYieldSyncSlot.toNumber() > 0, "sync slot should be recorded");
+    // Drift rounds scaled balances down, so value may sit a unit below principal
+    assert.ok(acct.lastSyncedValue.toNumber() >= principal - 1, "value should cover principal");
+    assert.equal(
+      acct.cumulativeYield.toNumber(),
+      Math.max(0, acct.lastSyncedValue.toNumber() - principal) + acct.yieldWithdrawn.toNumber()
+    );
+    console.log(`Synced value: ${acct.lastSyncedValue.toNumber() / 1e6} USDC, yield: ${acct.cumulativeYield.toNumber()}`);
+  });
+
+  it("Y5b. disable_drift — rejected for a non-owner, Drift stays attached", async () => {
+    const stranger = Keypair.generate();
+    try {
+      await program.methods
+        .disableDrift()
+        .accounts({
+          signer: stranger.publicKey,
+          silkAccount: accountPda,
+          accountTokenAccount: accountAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(buildDriftRemainingAccounts(accountPda))
+        .signers([stranger])
+        .rpc();
+      assert.fail("Should have failed — stranger is not an owner");
+    } catch (err: any) {
+      assert.include(err.toString(), "Unauthorized");
+    }
+
+    const acct = await program.account.silkAccount.fetch(accountPda);
+    assert.equal(acct.driftUser?.toString(), driftUser.toString());
+  });
+
+  it("Y5c. migrate_drift_market — rejects migrating to the current market", async () => {
+    try {
+      await program.methods
+        .migrateDriftMarket(USDC_MARKET_INDEX)
+        .accounts({
+          signer: owner.publicKey,
+          silkAccount: accountPda,
+          accountTokenAccount: accountAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(buildDriftRemainingAccounts(accountPda))
+        .signers([owner])
+        .rpc();
+      assert.fail("Should have failed — same market index");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidDriftSpotMarket");
+    }
+  });
+
+  it("Y5d. misordered Drift accounts — rejected with a specific error", async () => {
+    const misordered = buildDriftRemainingAccounts(accountPda);
+    // Swap spot_market_vault and drift_signer
+    [misordered[3], misordered[4]] = [misordered[4], misordered[3]];
+
+    try {
+      await program.methods
+        .transferFromAccount(new BN(1))
+        .accounts({
+          signer: owner.publicKey,
+          silkAccount: accountPda,
+          mint: USDC_MINT,
+          accountTokenAccount: accountAta,
+          recipient: owner.publicKey,
+          recipientTokenAccount: ownerAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(misordered)
+        .signers([owner])
+        .rpc();
+      assert.fail("Should have failed — misordered Drift accounts");
+    } catch (err: any) {
+      assert.include(err.toString(), "InvalidDriftSpotMarketVault");
+    }
+  });
+
+  it("Y5e. set_liquidity_buffer + rebalance — pulls the buffer out of Drift into the ATA", async () => {
+    const BUFFER = 1_000_000; // 1 USDC
+
+    await program.methods
+      .setLiquidityBuffer(0, new BN(BUFFER))
+      .accounts({
+        signer: owner.publicKey,
+        silkAccount: accountPda,
+      })
+      .signers([owner])
+      .rpc();
+
+    await program.methods
+      .rebalance()
+      .accounts({
+        caller: payer,
+        silkAccount: accountPda,
+        accountTokenAccount: accountAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(buildDriftRemainingAccounts(accountPda))
+      .rpc({ skipPreflight: true });
+
+    const ataBalance = await getTokenBalance(connection, accountAta);
+    assert.equal(ataBalance.toNumber(), BUFFER, "ATA should hold exactly the buffer");
+    console.log(`Rebalanced: ${ataBalance.toNumber() / 1e6} USDC liquid in the ATA`);
+  });
+
+  it("Y5f. disable_drift — pulls the whole position into the ATA and detaches Drift", async () => {
+    const before = await program.account.silkAccount.fetch(accountPda);
+
+    await program.methods
+      .disableDrift()
+      .accounts({
+        signer: owner.publicKey,
+        silkAccount: accountPda,
+        accountTokenAccount: accountAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(buildDriftRemainingAccounts(accountPda))
+      .signers([owner])
+      .rpc({ skipPreflight: true });
+
+    const acct = await program.account.silkAccount.fetch(accountPda);
+    assert.isNull(acct.driftUser);
+    assert.isNull(acct.driftMarketIndex);
+    assert.deepEqual(acct.yieldAdapter, { none: {} });
+    assert.equal(acct.principalBalance.toNumber(), before.principalBalance.toNumber());
+
+    // Drift rounds scaled balances down, so the ATA may sit a unit below principal
+    const ataBalance = await getTokenBalance(connection, accountAta);
+    assert.isAtLeast(ataBalance.toNumber(), before.principalBalance.toNumber() - 1);
+
+    // The Drift user and user stats stay behind for re-enabling or cleanup
+    assert.equal(acct.detachedDriftUser?.toString(), driftUser.toString());
+    assert.isNotNull(await connection.getAccountInfo(driftUser));
+    assert.isNotNull(await connection.getAccountInfo(driftUserStats));
+  });
+
+  it("Y5g. init_drift_user again — reuses the detached Drift user and redeposits", async () => {
+    const ataBefore = await getTokenBalance(connection, accountAta);
+
+    await program.methods
+      .initDriftUser(0, stringToBytes32("silkysig-test"), USDC_MARKET_INDEX)
+      .accounts({
+        signer: owner.publicKey,
+        silkAccount: accountPda,
+        mint: USDC_MINT,
+        accountTokenAccount: accountAta,
+        driftUser: driftUser,
+        driftUserStats: driftUserStats,
+        driftState: DRIFT_STATE,
+        driftSpotMarketVault: getDriftSpotMarketVaultPDA(USDC_MARKET_INDEX),
+        driftSpotMarket: getDriftSpotMarketPDA(USDC_MARKET_INDEX),
+        driftOracle: USDC_ORACLE,
+        driftProgram: DRIFT_PROGRAM,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: web3.SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ skipPreflight: true });
+
+    const acct = await program.account.silkAccount.fetch(accountPda);
+    assert.equal(acct.driftUser?.toString(), driftUser.toString());
+    assert.equal(acct.driftMarketIndex, USDC_MARKET_INDEX);
+    assert.deepEqual(acct.yieldAdapter, { drift: {} });
+    assert.isNull(acct.detachedDriftUser, "re-attached Drift user is no longer detached");
+
+    const ataAfter = await getTokenBalance(connection, accountAta);
+    assert.equal(ataAfter.toNumber(), 0, "ATA balance should be redeposited");
+    console.log(`Re-enabled Drift with ${ataBefore.toNumber() / 1e6} USDC`);
+  });
+
+  // Needs a second Drift spot market for the same mint: DRIFT_ALT_MARKET_INDEX
+  it("Y5h. migrate_drift_market — moves the position to another market and back", async function () {
+    const altMarket = process.env.DRIFT_ALT_MARKET_INDEX;
+    if (altMarket === undefined) this.skip();
+    const altMarketIndex = Number(altMarket);
+    const altOracle = await fetchSpotMarketOracle(connection, altMarketIndex);
+
+    const migrate = async (from: number, to: number, toOracle: PublicKey) => {
+      const current = buildDriftRemainingAccounts(accountPda);
+      current[3].pubkey = getDriftSpotMarketVaultPDA(from);
+      current[6].pubkey = await fetchSpotMarketOracle(connection, from);
+      current[7].pubkey = getDriftSpotMarketPDA(from);
+      await program.methods
+        .migrateDriftMarket(to)
+        .accounts({
+          signer: owner.publicKey,
+          silkAccount: accountPda,
+          accountTokenAccount: accountAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          ...current,
+          { pubkey: getDriftSpotMarketVaultPDA(to), isSigner: false, isWritable: true },
+          { pubkey: toOracle, isSigner: false, isWritable: false },
+          { pubkey: getDriftSpotMarketPDA(to), isSigner: false, isWritable: true },
+        ])
+        .signers([owner])
+        .rpc({ skipPreflight: true });
+    };
+
+    const before = await program.account.silkAccount.fetch(accountPda);
+
+    await migrate(USDC_MARKET_INDEX, altMarketIndex, altOracle);
+    let acct = await program.account.silkAccount.fetch(accountPda);
+    assert.equal(acct.driftMarketIndex, altMarketIndex);
+    assert.equal(acct.driftUser?.toString(), driftUser.toString());
+
+    await migrate(altMarketIndex, USDC_MARKET_INDEX, USDC_ORACLE);
+    acct = await program.account.silkAccount.fetch(accountPda);
+    assert.equal(acct.driftMarketIndex, USDC_MARKET_INDEX);
+    assert.equal(acct.principalBalance.toNumber(), before.principalBalance.toNumber());
+    assert.equal((await getTokenBalance(connection, accountAta)).toNumber(), 0);
+  });
+
+  it("Y5i. transfer_ownership — a new owner takes over the Drift-enabled account", async () => {
+    const fundTx = new Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: payer,
+        toPubkey: newOwner.publicKey,
+        lamports: 0.05 * web3.LAMPORTS_PER_SOL,
+      })
+    );
+    await provider.sendAndConfirm(fundTx);
+
+    await program.methods
+      .transferOwnership(newOwner.publicKey)
+      .accounts({
+        signer: owner.publicKey,
+        silkAccount: accountPda,
+      })
+      .signers([owner])
+      .rpc();
+
+    await program.methods
+      .acceptOwnership()
+      .accounts({
+        newOwner: newOwner.publicKey,
+        silkAccount: accountPda,
+      })
+      .signers([newOwner])
+      .rpc();
+
+    const acct = await program.account.silkAccount.fetch(accountPda);
+    assert.equal(acct.owner.toString(), newOwner.publicKey.toString());
+    assert.equal(acct.creator.toString(), owner.publicKey.toString());
+  });
+
   it("Y6. close_account with Drift — withdraws remaining from Drift, sweeps to owner", async () => {
     const acctBefore = await program.account.silkAccount.fetch(accountPda);
     const remainingPrincipal = acctBefore.principalBalance.toNumber();
     console.log(`Remaining principal before close: ${remainingPrincipal / 1e6} USDC`);
 
-    const ownerBalBefore = await getTokenBalance(connection, ownerAta);
+    const ownerBalBefore = await getTokenBalance(connection, newOwnerAta).catch(() => new BN(0));
+    const driftCleanup = findDriftCleanupPda(programId, accountPda);
 
     // Build instruction manually — Anchor TS close constraint conflicts with remainingAccounts
     const closeIx = await program.methods
       .closeAccount()
       .accounts({
-        owner: owner.publicKey,
+        signer: newOwner.publicKey,
+        owner: newOwner.publicKey,
         silkAccount: accountPda,
         mint: USDC_MINT,
         accountTokenAccount: accountAta,
-        ownerTokenAccount: ownerAta,
+        ownerTokenAccount: newOwnerAta,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        driftCleanup,
       })
-      .remainingAccounts(buildWithdrawRemainingAccounts(accountPda))
+      .remainingAccounts(buildDriftRemainingAccounts(accountPda))
       .instruction();
 
     const closeTx = new Transaction().add(closeIx);
-    closeTx.feePayer = owner.publicKey;
+    closeTx.feePayer = newOwner.publicKey;
     closeTx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-    closeTx.sign(owner);
+    closeTx.sign(newOwner);
 
     const closeSig = await connection.sendRawTransaction(closeTx.serialize(), { skipPreflight: true });
     console.log(`Sent close_account tx: ${closeSig}`);
@@ -460,7 +748,7 @@ describe("silkysig-yield (devnet)", () => {
     if (closeErr) throw closeErr;
 
     // Owner should have received the remaining USDC (plus any yield accrued)
-    const ownerBalAfter = await getTokenBalance(connection, ownerAta);
+    const ownerBalAfter = await getTokenBalance(connection, newOwnerAta);
     const received = ownerBalAfter.sub(ownerBalBefore).toNumber();
     // Allow 1 unit rounding loss from Drift's scaled balance accounting
     assert.isAtLeast(received, remainingPrincipal - 1, "Owner should receive approximately the principal");
@@ -474,6 +762,214 @@ describe("silkysig-yield (devnet)", () => {
     const ataInfo = await connection.getAccountInfo(accountAta);
     assert.isNull(ataInfo, "Account ATA should be closed");
 
+    // The closing owner is recorded so the Drift rent follows them, not the creator
+    const record = await program.account.driftCleanup.fetch(driftCleanup);
+    assert.equal(record.owner.toString(), newOwner.publicKey.toString());
+    assert.equal(record.account.toString(), accountPda.toString());
+    assert.equal(record.driftUser.toString(), driftUser.toString());
+
     console.log("Account closed successfully");
+    console.log(`Run Y8 after Drift's idle period with IDLE_SILK_CREATOR=${owner.publicKey.toString()}`);
+  });
+
+  it("Y7. cleanup_drift_user before Drift's idle period — rejected, Drift user kept", async () => {
+    try {
+      await program.methods
+        .cleanupDriftUser(owner.publicKey, USDC_MINT)
+        .accounts({
+          caller: payer,
+          owner: newOwner.publicKey,
+          silkAccount: accountPda,
+          driftUser: driftUser,
+          driftUserStats: driftUserStats,
+          driftState: DRIFT_STATE,
+          driftProgram: DRIFT_PROGRAM,
+          systemProgram: SystemProgram.programId,
+          driftCleanup: findDriftCleanupPda(programId, accountPda),
+        })
+        .rpc();
+      assert.fail("Should have failed — Drift user not idle yet");
+    } catch (err: any) {
+      assert.include(err.toString(), "DriftDeleteUserFailed");
+    }
+
+    const driftUserInfo = await connection.getAccountInfo(driftUser);
+    assert.isNotNull(driftUserInfo, "Drift user should still exist");
+  });
+
+  // Drift only deletes users idle for ~13 days, so this runs against an account
+  // closed by an earlier run: IDLE_SILK_CREATOR is the creator printed by Y6.
+  it("Y8. cleanup_drift_user after Drift's idle period — rent goes to the owner who closed", async function () {
+    const idleCreator = process.env.IDLE_SILK_CREATOR;
+    if (!idleCreator) this.skip();
+
+    const creator = new PublicKey(idleCreator);
+    const [idlePda] = findAccountPda(programId, creator, USDC_MINT);
+    const idleDriftUser = getDriftUserPDA(idlePda, 0);
+    const idleCleanup = findDriftCleanupPda(programId, idlePda);
+
+    const record = await program.account.driftCleanup.fetch(idleCleanup);
+    const recordRent = await connection.getBalance(idleCleanup);
+    const driftUserRent = await connection.getBalance(idleDriftUser);
+    const ownerLamportsBefore = await connection.getBalance(record.owner);
+
+    await program.methods
+      .cleanupDriftUser(creator, USDC_MINT)
+      .accounts({
+        caller: payer,
+        owner: record.owner,
+        silkAccount: idlePda,
+        driftUser: idleDriftUser,
+        driftUserStats: getDriftUserStatsPDA(idlePda),
+        driftState: DRIFT_STATE,
+        driftProgram: DRIFT_PROGRAM,
+        systemProgram: SystemProgram.programId,
+        driftCleanup: idleCleanup,
+      })
+      .rpc();
+
+    assert.isNull(await connection.getAccountInfo(idleDriftUser), "Drift user should be deleted");
+    assert.isNull(await connection.getAccountInfo(idleCleanup), "Record should be closed");
+
+    const ownerLamportsAfter = await connection.getBalance(record.owner);
+    assert.equal(ownerLamportsAfter - ownerLamportsBefore, driftUserRent + recordRent);
+    console.log(`Refunded ${(driftUserRent + recordRent) / web3.LAMPORTS_PER_SOL} SOL to ${record.owner.toString()}`);
+  });
+
+  it("Y9. disable_drift then close_account — the detached Drift user is still recorded for cleanup", async () => {
+    const detachedOwner = Keypair.generate();
+    const [pda] = findAccountPda(programId, detachedOwner.publicKey, USDC_MINT);
+    const ata = getAta(USDC_MINT, pda);
+    const detachedOwnerAta = getAta(USDC_MINT, detachedOwner.publicKey);
+    const pdaDriftUser = getDriftUserPDA(pda, 0);
+    const pdaDriftUserStats = getDriftUserStatsPDA(pda);
+    const driftCleanup = findDriftCleanupPda(programId, pda);
+
+    await provider.sendAndConfirm(new Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: payer,
+        toPubkey: detachedOwner.publicKey,
+        lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+      }),
+      createAssociatedTokenAccountIdempotentInstruction(payer, detachedOwnerAta, detachedOwner.publicKey, USDC_MINT),
+      createTransferInstruction(getAta(USDC_MINT, payer), detachedOwnerAta, payer, 1_000_000),
+    ));
+
+    await program.methods
+      .createAccount()
+      .accounts({
+        owner: detachedOwner.publicKey,
+        mint: USDC_MINT,
+        silkAccount: pda,
+        accountTokenAccount: ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([detachedOwner])
+      .rpc();
+
+    await program.methods
+      .deposit(new BN(1_000_000))
+      .accounts({
+        depositor: detachedOwner.publicKey,
+        silkAccount: pda,
+        mint: USDC_MINT,
+        accountTokenAccount: ata,
+        depositorTokenAccount: detachedOwnerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([detachedOwner])
+      .rpc();
+
+    await program.methods
+      .initDriftUser(0, stringToBytes32("silkysig-detached"), USDC_MARKET_INDEX)
+      .accounts({
+        signer: detachedOwner.publicKey,
+        silkAccount: pda,
+        mint: USDC_MINT,
+        accountTokenAccount: ata,
+        driftUser: pdaDriftUser,
+        driftUserStats: pdaDriftUserStats,
+        driftState: DRIFT_STATE,
+        driftSpotMarketVault: getDriftSpotMarketVaultPDA(USDC_MARKET_INDEX),
+        driftSpotMarket: getDriftSpotMarketPDA(USDC_MARKET_INDEX),
+        driftOracle: USDC_ORACLE,
+        driftProgram: DRIFT_PROGRAM,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: web3.SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([detachedOwner])
+      .rpc({ skipPreflight: true });
+
+    await program.methods
+      .disableDrift()
+      .accounts({
+        signer: detachedOwner.publicKey,
+        silkAccount: pda,
+        accountTokenAccount: ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(buildDriftRemainingAccounts(pda))
+      .signers([detachedOwner])
+      .rpc({ skipPreflight: true });
+
+    const closeAccounts = {
+      signer: detachedOwner.publicKey,
+      owner: detachedOwner.publicKey,
+      silkAccount: pda,
+      mint: USDC_MINT,
+      accountTokenAccount: ata,
+      ownerTokenAccount: detachedOwnerAta,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // No Drift adapter is attached, but the detached Drift user still needs its record
+    try {
+      await program.methods
+        .closeAccount()
+        .accounts({ ...closeAccounts, driftCleanup: null })
+        .signers([detachedOwner])
+        .rpc();
+      assert.fail("Should have failed — detached Drift user needs a cleanup record");
+    } catch (err: any) {
+      assert.include(err.toString(), "MissingDriftCleanup");
+    }
+
+    await program.methods
+      .closeAccount()
+      .accounts({ ...closeAccounts, driftCleanup })
+      .signers([detachedOwner])
+      .rpc();
+
+    assert.isNull(await connection.getAccountInfo(pda), "Silk account should be closed");
+    const record = await program.account.driftCleanup.fetch(driftCleanup);
+    assert.equal(record.owner.toString(), detachedOwner.publicKey.toString());
+    assert.equal(record.driftUser.toString(), pdaDriftUser.toString());
+
+    // The record is found, so cleanup only waits on Drift's idle period
+    try {
+      await program.methods
+        .cleanupDriftUser(detachedOwner.publicKey, USDC_MINT)
+        .accounts({
+          caller: payer,
+          owner: detachedOwner.publicKey,
+          silkAccount: pda,
+          driftUser: pdaDriftUser,
+          driftUserStats: pdaDriftUserStats,
+          driftState: DRIFT_STATE,
+          driftProgram: DRIFT_PROGRAM,
+          systemProgram: SystemProgram.programId,
+          driftCleanup,
+        })
+        .rpc();
+      assert.fail("Should have failed — Drift user not idle yet");
+    } catch (err: any) {
+      assert.include(err.toString(), "DriftDeleteUserFailed");
+    }
+    assert.isNotNull(await connection.getAccountInfo(pdaDriftUser), "Drift user should still exist");
   });
 });
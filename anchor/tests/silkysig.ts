@@ -6,34 +6,81 @@ import {
   mintTo,
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createInitializeNonTransferableMintInstruction,
+  createInitializeTransferHookInstruction,
+  getExtraAccountMetaAddress,
 } from "@solana/spl-token";
 import {
   PublicKey,
   SystemProgram,
   Keypair,
   Transaction,
+  SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import { assert } from "chai";
 import { Silkysig } from "../target/types/silkysig";
+import { Handshake } from "../target/types/handshake";
+import { TransferHookCounter } from "../target/types/transfer_hook_counter";
 
-// PDA seed constant (must match on-chain constant)
+// PDA seed constants (must match on-chain constants)
 const ACCOUNT_SEED = Buffer.from("account");
+const PENDING_TRANSFER_SEED = Buffer.from("pending_transfer");
+const RECURRING_PAYMENT_SEED = Buffer.from("recurring_payment");
+
+// Operator permission bits (mirror OPERATOR_* in state/account.rs)
+const OPERATOR_TRANSFER = 1 << 0;
+const OPERATOR_SET_BUFFER = 1 << 1;
+const OPERATOR_CREATE_ESCROW = 1 << 2;
+const OPERATOR_PAUSE = 1 << 3;
+const OPERATOR_MANAGE_VENUE = 1 << 4;
 
 // ─── Helpers ───────────────────────────────────────────────────────────────────
 
 function findAccountPda(
   programId: PublicKey,
-  owner: PublicKey
+  owner: PublicKey,
+  mint: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [ACCOUNT_SEED, owner.toBuffer(), mint.toBuffer()],
+    programId
+  );
+}
+
+function findPendingTransferPda(
+  programId: PublicKey,
+  account: PublicKey,
+  id: BN
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [ACCOUNT_SEED, owner.toBuffer()],
+    [PENDING_TRANSFER_SEED, account.toBuffer(), id.toArrayLike(Buffer, "le", 8)],
     programId
   );
 }
 
-function getAta(mint: PublicKey, owner: PublicKey): PublicKey {
-  return getAssociatedTokenAddressSync(mint, owner, true);
+function findRecurringPaymentPda(
+  programId: PublicKey,
+  account: PublicKey,
+  id: BN
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [RECURRING_PAYMENT_SEED, account.toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+function getAta(
+  mint: PublicKey,
+  owner: PublicKey,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): PublicKey {
+  return getAssociatedTokenAddressSync(mint, owner, true, tokenProgram);
 }
 
 async function getTokenBalance(
@@ -119,7 +166,7 @@ describe("silkysig", () => {
     }
 
     // Derive account PDA
-    [accountPda, accountBump] = findAccountPda(programId, owner.publicKey);
+    [accountPda, accountBump] = findAccountPda(programId, owner.publicKey, mint);
   });
 
   // ═══════════════════════════════════════════════════════════════════════════
@@ -143,20 +190,21 @@ describe("silkysig", () => {
         .rpc();
 
       await program.methods
-        .addOperator(operator.publicKey, PER_TX_LIMIT)
+        .addOperator(operator.publicKey, PER_TX_LIMIT, false, OPERATOR_TRANSFER)
         .accounts({
-          owner: owner.publicKey,
+          signer: owner.publicKey,
           silkAccount: accountPda,
         })
         .signers([owner])
         .rpc();
 
       const account = await program.account.silkAccount.fetch(accountPda);
-      assert.equal(account.version, 1);
+      assert.equal(account.version, 2);
       assert.equal(account.bump, accountBump);
       assert.equal(account.owner.toString(), owner.publicKey.toString());
       assert.equal(account.mint.toString(), mint.toString());
       assert.equal(account.isPaused, false);
+      assert.deepEqual(account.yieldAdapter, { none: {} });
       assert.equal(account.operatorCount, 1);
       assert.equal(account.operators[0].pubkey.toString(), operator.publicKey.toString());
       assert.equal(account.operators[0].perTxLimit.toString(), PER_TX_LIMIT.toString());
@@ -167,7 +215,7 @@ describe("silkysig", () => {
     });
 
     it("A2. creates account without operator", async () => {
-      const [account2Pda] = findAccountPda(programId, owner2.publicKey);
+      const [account2Pda] = findAccountPda(programId, owner2.publicKey, mint);
 
       await program.methods
         .createAccount()
@@ -212,6 +260,34 @@ describe("silkysig", () => {
         assert.ok(err.toString().length > 0);
       }
     });
+
+    it("A4. same owner creates a second account for a different mint", async () => {
+      const mint2 = await createMint(connection, payerKeypair, payerKeypair.publicKey, null, 6);
+      const [mint2Pda] = findAccountPda(programId, owner.publicKey, mint2);
+      assert.notEqual(mint2Pda.toString(), accountPda.toString());
+
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: owner.publicKey,
+          mint: mint2,
+          silkAccount: mint2Pda,
+          accountTokenAccount: getAta(mint2, mint2Pda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(mint2Pda);
+      assert.equal(account.owner.toString(), owner.publicKey.toString());
+      assert.equal(account.mint.toString(), mint2.toString());
+
+      // Original account is untouched
+      const original = await program.account.silkAccount.fetch(accountPda);
+      assert.equal(original.mint.toString(), mint.toString());
+    });
   });
 
   // ═══════════════════════════════════════════════════════════════════════════
@@ -446,7 +522,7 @@ describe("silkysig", () => {
       await mintTo(connection, payerKeypair, mint, ownerAta, payerKeypair, 50_000_000);
 
       // Create account, then add operator with no limit (defaults to u64::MAX = unlimited)
-      const [unlimitedPda] = findAccountPda(programId, unlimitedOwner.publicKey);
+      const [unlimitedPda] = findAccountPda(programId, unlimitedOwner.publicKey, mint);
 
       await program.methods
         .createAccount()
@@ -463,9 +539,9 @@ describe("silkysig", () => {
         .rpc();
 
       await program.methods
-        .addOperator(unlimitedOperator.publicKey, null)
+        .addOperator(unlimitedOperator.publicKey, null, false, OPERATOR_TRANSFER)
         .accounts({
-          owner: unlimitedOwner.publicKey,
+          signer: unlimitedOwner.publicKey,
           silkAccount: unlimitedPda,
         })
         .signers([unlimitedOwner])
@@ -544,15 +620,15 @@ describe("silkysig", () => {
   });
 
   // ═══════════════════════════════════════════════════════════════════════════
-  // Group D: Toggle Pause
+  // Group D: Pause
   // ═══════════════════════════════════════════════════════════════════════════
 
-  describe("D. Toggle Pause", () => {
-    it("D1. toggle_pause flips false→true", async () => {
+  describe("D. Pause", () => {
+    it("D1. set_pause(true) pauses the account", async () => {
       await program.methods
-        .togglePause()
+        .setPause(true, null)
         .accounts({
-          owner: owner.publicKey,
+          signer: owner.publicKey,
           silkAccount: accountPda,
         })
         .signers([owner])
@@ -587,11 +663,11 @@ describe("silkysig", () => {
       }
     });
 
-    it("D3. toggle_pause flips true→false", async () => {
+    it("D3. set_pause(false) unpauses the account", async () => {
       await program.methods
-        .togglePause()
+        .setPause(false, null)
         .accounts({
-          owner: owner.publicKey,
+          signer: owner.publicKey,
           silkAccount: accountPda,
         })
         .signers([owner])
@@ -623,21 +699,75 @@ describe("silkysig", () => {
       // If we got here without error, it succeeded
     });
 
-    it("D5. toggle_pause by non-owner fails", async () => {
+    it("D5. set_pause by non-owner fails", async () => {
       try {
         await program.methods
-          .togglePause()
+          .setPause(true, null)
           .accounts({
-            owner: outsider.publicKey,
+            signer: outsider.publicKey,
             silkAccount: accountPda,
           })
           .signers([outsider])
           .rpc();
         assert.fail("Should have failed — non-owner");
       } catch (err: any) {
-        // Seeds mismatch: outsider's key derives a different PDA
-        assert.include(err.toString(), "AnchorError");
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+
+    it("D6. pause guardian can pause (idempotently) but not unpause", async () => {
+      const pauseGuardian = Keypair.generate();
+      const INCIDENT_REASON = 3;
+
+      await program.methods
+        .setPauseGuardian(pauseGuardian.publicKey)
+        .accounts({
+          signer: owner.publicKey,
+          silkAccount: accountPda,
+        })
+        .signers([owner])
+        .rpc();
+
+      // Two racing pauses leave the account paused
+      for (let i = 0; i < 2; i++) {
+        await program.methods
+          .setPause(true, INCIDENT_REASON)
+          .accounts({
+            signer: pauseGuardian.publicKey,
+            silkAccount: accountPda,
+          })
+          .signers([pauseGuardian])
+          .rpc();
+      }
+
+      let account = await program.account.silkAccount.fetch(accountPda);
+      assert.equal(account.isPaused, true);
+
+      try {
+        await program.methods
+          .setPause(false, null)
+          .accounts({
+            signer: pauseGuardian.publicKey,
+            silkAccount: accountPda,
+          })
+          .signers([pauseGuardian])
+          .rpc();
+        assert.fail("Should have failed — guardian unpause");
+      } catch (err: any) {
+        assert.include(err.toString(), "GuardianCannotUnpause");
       }
+
+      await program.methods
+        .setPause(false, null)
+        .accounts({
+          signer: owner.publicKey,
+          silkAccount: accountPda,
+        })
+        .signers([owner])
+        .rpc();
+
+      account = await program.account.silkAccount.fetch(accountPda);
+      assert.equal(account.isPaused, false);
     });
   });
 
@@ -658,9 +788,9 @@ describe("silkysig", () => {
 
     it("E1. add second operator, verify operator_count == 2", async () => {
       await program.methods
-        .addOperator(operator2.publicKey, new BN(10_000_000))
+        .addOperator(operator2.publicKey, new BN(10_000_000), false, OPERATOR_TRANSFER)
         .accounts({
-          owner: owner.publicKey,
+          signer: owner.publicKey,
           silkAccount: accountPda,
         })
         .signers([owner])
@@ -675,9 +805,9 @@ describe("silkysig", () => {
     it("E2. add duplicate operator fails", async () => {
       try {
         await program.methods
-          .addOperator(operator2.publicKey, new BN(5_000_000))
+          .addOperator(operator2.publicKey, new BN(5_000_000), false, OPERATOR_TRANSFER)
           .accounts({
-            owner: owner.publicKey,
+            signer: owner.publicKey,
             silkAccount: accountPda,
           })
           .signers([owner])
@@ -690,9 +820,9 @@ describe("silkysig", () => {
 
     it("E3. add third (max) operator, verify operator_count == 3", async () => {
       await program.methods
-        .addOperator(operator3.publicKey, new BN(1_000_000))
+        .addOperator(operator3.publicKey, new BN(1_000_000), false, OPERATOR_TRANSFER)
         .accounts({
-          owner: owner.publicKey,
+          signer: owner.publicKey,
           silkAccount: accountPda,
         })
         .signers([owner])
@@ -705,9 +835,9 @@ describe("silkysig", () => {
     it("E4. add fourth operator fails — MaxOperatorsReached", async () => {
       try {
         await program.methods
-          .addOperator(operator4.publicKey, new BN(1_000_000))
+          .addOperator(operator4.publicKey, new BN(1_000_000), false, OPERATOR_TRANSFER)
           .accounts({
-            owner: owner.publicKey,
+            signer: owner.publicKey,
             silkAccount: accountPda,
           })
           .signers([owner])
@@ -725,7 +855,7 @@ describe("silkysig", () => {
       await program.methods
         .removeOperator(operator2.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          signer: owner.publicKey,
           silkAccount: accountPda,
         })
         .signers([owner])
@@ -744,7 +874,7 @@ describe("silkysig", () => {
         await program.methods
           .removeOperator(operator4.publicKey)
           .accounts({
-            owner: owner.publicKey,
+            signer: owner.publicKey,
             silkAccount: accountPda,
           })
           .signers([owner])
@@ -758,31 +888,30 @@ describe("silkysig", () => {
     it("E7. add/remove by non-owner fails", async () => {
       try {
         await program.methods
-          .addOperator(operator4.publicKey, new BN(1_000_000))
+          .addOperator(operator4.publicKey, new BN(1_000_000), false, OPERATOR_TRANSFER)
           .accounts({
-            owner: outsider.publicKey,
+            signer: outsider.publicKey,
             silkAccount: accountPda,
           })
           .signers([outsider])
           .rpc();
         assert.fail("Should have failed — non-owner add");
       } catch (err: any) {
-        // Seeds mismatch: outsider's key derives a different PDA
-        assert.include(err.toString(), "AnchorError");
+        assert.include(err.toString(), "Unauthorized");
       }
 
       try {
         await program.methods
           .removeOperator(operator.publicKey)
           .accounts({
-            owner: outsider.publicKey,
+            signer: outsider.publicKey,
             silkAccount: accountPda,
           })
           .signers([outsider])
           .rpc();
         assert.fail("Should have failed — non-owner remove");
       } catch (err: any) {
-        assert.include(err.toString(), "AnchorError");
+        assert.include(err.toString(), "Unauthorized");
       }
     });
   });
@@ -819,7 +948,7 @@ describe("silkysig", () => {
       await mintTo(connection, payerKeypair, mint, ata, payerKeypair, 50_000_000);
 
       // Derive PDA
-      [closePda, closeBump] = findAccountPda(programId, closeOwner.publicKey);
+      [closePda, closeBump] = findAccountPda(programId, closeOwner.publicKey, mint);
 
       // Create account
       await program.methods
@@ -857,6 +986,7 @@ describe("silkysig", () => {
       await program.methods
         .closeAccount()
         .accounts({
+          signer: closeOwner.publicKey,
           owner: closeOwner.publicKey,
           silkAccount: closePda,
           mint,
@@ -865,6 +995,7 @@ describe("silkysig", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          driftCleanup: null,
         })
         .signers([closeOwner])
         .rpc();
@@ -894,7 +1025,7 @@ describe("silkysig", () => {
       );
       await provider.sendAndConfirm(fundTx);
 
-      const [zeroPda] = findAccountPda(programId, zeroOwner.publicKey);
+      const [zeroPda] = findAccountPda(programId, zeroOwner.publicKey, mint);
 
       await program.methods
         .createAccount()
@@ -913,6 +1044,7 @@ describe("silkysig", () => {
       await program.methods
         .closeAccount()
         .accounts({
+          signer: zeroOwner.publicKey,
           owner: zeroOwner.publicKey,
           silkAccount: zeroPda,
           mint,
@@ -921,6 +1053,7 @@ describe("silkysig", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          driftCleanup: null,
         })
         .signers([zeroOwner])
         .rpc();
@@ -942,7 +1075,7 @@ describe("silkysig", () => {
       );
       await provider.sendAndConfirm(fundTx);
 
-      const [protectedPda] = findAccountPda(programId, protectedOwner.publicKey);
+      const [protectedPda] = findAccountPda(programId, protectedOwner.publicKey, mint);
 
       await program.methods
         .createAccount()
@@ -962,22 +1095,1591 @@ describe("silkysig", () => {
         await program.methods
           .closeAccount()
           .accounts({
-            owner: outsider.publicKey,
+            signer: outsider.publicKey,
+            owner: protectedOwner.publicKey,
             silkAccount: protectedPda,
             mint,
             accountTokenAccount: getAta(mint, protectedPda),
-            ownerTokenAccount: getAta(mint, outsider.publicKey),
+            ownerTokenAccount: getAta(mint, protectedOwner.publicKey),
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            driftCleanup: null,
           })
           .signers([outsider])
           .rpc();
         assert.fail("Should have failed — non-owner close");
       } catch (err: any) {
-        // PDA seed derivation uses owner key, so non-owner will fail seed check
-        assert.ok(err.toString().length > 0);
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group G: Owner Set (M-of-N)
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("G. Owner Set", () => {
+    let teamOwner: Keypair;
+    let coOwnerA: Keypair;
+    let coOwnerB: Keypair;
+    let teamPda: PublicKey;
+
+    before(async () => {
+      teamOwner = Keypair.generate();
+      coOwnerA = Keypair.generate();
+      coOwnerB = Keypair.generate();
+
+      const tx = new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: payer,
+          toPubkey: teamOwner.publicKey,
+          lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+        }),
+        SystemProgram.transfer({
+          fromPubkey: payer,
+          toPubkey: coOwnerA.publicKey,
+          lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(tx);
+
+      [teamPda] = findAccountPda(programId, teamOwner.publicKey, mint);
+
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: teamOwner.publicKey,
+          mint,
+          silkAccount: teamPda,
+          accountTokenAccount: getAta(mint, teamPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([teamOwner])
+        .rpc();
+    });
+
+    it("G1. owner configures a 2-of-3 owner set", async () => {
+      await program.methods
+        .setOwnerSet([coOwnerA.publicKey, coOwnerB.publicKey], 2)
+        .accounts({
+          signer: teamOwner.publicKey,
+          silkAccount: teamPda,
+        })
+        .signers([teamOwner])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(teamPda);
+      assert.equal(account.coOwnerCount, 2);
+      assert.equal(account.ownerThreshold, 2);
+      assert.equal(account.coOwners[0].toString(), coOwnerA.publicKey.toString());
+      assert.equal(account.coOwners[1].toString(), coOwnerB.publicKey.toString());
+    });
+
+    it("G2. owner alone can no longer pause", async () => {
+      try {
+        await program.methods
+          .setPause(true, null)
+          .accounts({
+            signer: teamOwner.publicKey,
+            silkAccount: teamPda,
+          })
+          .signers([teamOwner])
+          .rpc();
+        assert.fail("Should have failed — below threshold");
+      } catch (err: any) {
+        assert.include(err.toString(), "InsufficientOwnerApprovals");
+      }
+    });
+
+    it("G3. co-owner plus co-signer reaches threshold", async () => {
+      await program.methods
+        .setPause(true, null)
+        .accounts({
+          signer: coOwnerA.publicKey,
+          silkAccount: teamPda,
+        })
+        .remainingAccounts([
+          { pubkey: coOwnerB.publicKey, isSigner: true, isWritable: false },
+        ])
+        .signers([coOwnerA, coOwnerB])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(teamPda);
+      assert.equal(account.isPaused, true);
+    });
+
+    it("G4. rejects threshold above set size", async () => {
+      try {
+        await program.methods
+          .setOwnerSet([coOwnerA.publicKey], 3)
+          .accounts({
+            signer: teamOwner.publicKey,
+            silkAccount: teamPda,
+          })
+          .remainingAccounts([
+            { pubkey: coOwnerA.publicKey, isSigner: true, isWritable: false },
+          ])
+          .signers([teamOwner, coOwnerA])
+          .rpc();
+        assert.fail("Should have failed — threshold > members");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidOwnerSet");
+      }
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group H: Ownership Transfer & Recovery
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("H. Ownership Transfer & Recovery", () => {
+    let oldOwner: Keypair;
+    let newOwner: Keypair;
+    let guardian1: Keypair;
+    let guardian2: Keypair;
+    let rotatePda: PublicKey;
+
+    const RECOVERY_DELAY = new BN(24 * 60 * 60);
+
+    before(async () => {
+      oldOwner = Keypair.generate();
+      newOwner = Keypair.generate();
+      guardian1 = Keypair.generate();
+      guardian2 = Keypair.generate();
+
+      const tx = new Transaction();
+      for (const kp of [oldOwner, newOwner, guardian1]) {
+        tx.add(
+          SystemProgram.transfer({
+            fromPubkey: payer,
+            toPubkey: kp.publicKey,
+            lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+          })
+        );
+      }
+      await provider.sendAndConfirm(tx);
+
+      [rotatePda] = findAccountPda(programId, oldOwner.publicKey, mint);
+
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: oldOwner.publicKey,
+          mint,
+          silkAccount: rotatePda,
+          accountTokenAccount: getAta(mint, rotatePda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([oldOwner])
+        .rpc();
+    });
+
+    it("H1. accept by a key other than the pending owner fails", async () => {
+      await program.methods
+        .transferOwnership(newOwner.publicKey)
+        .accounts({
+          signer: oldOwner.publicKey,
+          silkAccount: rotatePda,
+        })
+        .signers([oldOwner])
+        .rpc();
+
+      try {
+        await program.methods
+          .acceptOwnership()
+          .accounts({
+            newOwner: outsider.publicKey,
+            silkAccount: rotatePda,
+          })
+          .signers([outsider])
+          .rpc();
+        assert.fail("Should have failed — not the pending owner");
+      } catch (err: any) {
+        assert.include(err.toString(), "NoPendingOwnershipTransfer");
+      }
+    });
+
+    it("H2. pending owner accepts; PDA is preserved and old owner loses control", async () => {
+      await program.methods
+        .acceptOwnership()
+        .accounts({
+          newOwner: newOwner.publicKey,
+          silkAccount: rotatePda,
+        })
+        .signers([newOwner])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(rotatePda);
+      assert.equal(account.owner.toString(), newOwner.publicKey.toString());
+      assert.equal(account.creator.toString(), oldOwner.publicKey.toString());
+      assert.isNull(account.pendingOwner);
+
+      try {
+        await program.methods
+          .setPause(true, null)
+          .accounts({
+            signer: oldOwner.publicKey,
+            silkAccount: rotatePda,
+          })
+          .signers([oldOwner])
+          .rpc();
+        assert.fail("Should have failed — old owner");
+      } catch (err: any) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+
+    it("H3. guardian-initiated recovery is timelocked and owner can cancel", async () => {
+      await program.methods
+        .setGuardians([guardian1.publicKey, guardian2.publicKey], 1, RECOVERY_DELAY)
+        .accounts({
+          signer: newOwner.publicKey,
+          silkAccount: rotatePda,
+        })
+        .signers([newOwner])
+        .rpc();
+
+      await program.methods
+        .initiateRecovery(oldOwner.publicKey)
+        .accounts({
+          guardian: guardian1.publicKey,
+          silkAccount: rotatePda,
+        })
+        .signers([guardian1])
+        .rpc();
+
+      let account = await program.account.silkAccount.fetch(rotatePda);
+      assert.equal(account.recoveryOwner.toString(), oldOwner.publicKey.toString());
+
+      try {
+        await program.methods
+          .completeRecovery()
+          .accounts({
+            caller: outsider.publicKey,
+            silkAccount: rotatePda,
+          })
+          .signers([outsider])
+          .rpc();
+        assert.fail("Should have failed — timelock active");
+      } catch (err: any) {
+        assert.include(err.toString(), "RecoveryTimelockActive");
       }
+
+      await program.methods
+        .cancelRecovery()
+        .accounts({
+          signer: newOwner.publicKey,
+          silkAccount: rotatePda,
+        })
+        .signers([newOwner])
+        .rpc();
+
+      account = await program.account.silkAccount.fetch(rotatePda);
+      assert.isNull(account.recoveryOwner);
+      assert.equal(account.owner.toString(), newOwner.publicKey.toString());
+    });
+
+    it("H4. clearing the guardians cancels an in-flight recovery", async () => {
+      await program.methods
+        .initiateRecovery(oldOwner.publicKey)
+        .accounts({
+          guardian: guardian1.publicKey,
+          silkAccount: rotatePda,
+        })
+        .signers([guardian1])
+        .rpc();
+
+      await program.methods
+        .setGuardians([], 0, new BN(0))
+        .accounts({
+          signer: newOwner.publicKey,
+          silkAccount: rotatePda,
+        })
+        .signers([newOwner])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(rotatePda);
+      assert.isNull(account.recoveryOwner);
+      assert.equal(account.recoveryEta.toNumber(), 0);
+
+      try {
+        await program.methods
+          .completeRecovery()
+          .accounts({
+            caller: outsider.publicKey,
+            silkAccount: rotatePda,
+          })
+          .signers([outsider])
+          .rpc();
+        assert.fail("Should have failed — recovery was cancelled");
+      } catch (err: any) {
+        assert.include(err.toString(), "NoRecoveryPending");
+      }
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group I: Pending Transfers (approval queue)
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("I. Pending Transfers", () => {
+    const OVER_LIMIT = new BN(8_000_000); // $8 > $5 operator limit
+
+    async function requestTransfer(amount: BN): Promise<PublicKey> {
+      const account = await program.account.silkAccount.fetch(accountPda);
+      const [pendingPda] = findPendingTransferPda(
+        programId,
+        accountPda,
+        account.nextPendingTransferId
+      );
+      const expiresAt = new BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .requestTransfer(amount, expiresAt)
+        .accounts({
+          operator: operator.publicKey,
+          silkAccount: accountPda,
+          pendingTransfer: pendingPda,
+          recipient: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([operator])
+        .rpc();
+
+      return pendingPda;
+    }
+
+    before(async () => {
+      await program.methods
+        .deposit(new BN(20_000_000))
+        .accounts({
+          depositor: owner.publicKey,
+          silkAccount: accountPda,
+          mint,
+          accountTokenAccount: getAta(mint, accountPda),
+          depositorTokenAccount: getAta(mint, owner.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([owner])
+        .rpc();
+    });
+
+    it("I1. operator requests an over-limit transfer and owner approves it", async () => {
+      const pendingPda = await requestTransfer(OVER_LIMIT);
+
+      const pending = await program.account.pendingTransfer.fetch(pendingPda);
+      assert.equal(pending.requester.toString(), operator.publicKey.toString());
+      assert.equal(pending.amount.toString(), OVER_LIMIT.toString());
+
+      const recipBalBefore = await getTokenBalance(connection, getAta(mint, recipient.publicKey));
+
+      await program.methods
+        .approvePendingTransfer()
+        .accounts({
+          signer: owner.publicKey,
+          silkAccount: accountPda,
+          pendingTransfer: pendingPda,
+          requester: operator.publicKey,
+          mint,
+          accountTokenAccount: getAta(mint, accountPda),
+          recipient: recipient.publicKey,
+          recipientTokenAccount: getAta(mint, recipient.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+
+      const recipBalAfter = await getTokenBalance(connection, getAta(mint, recipient.publicKey));
+      assert.equal(recipBalAfter.sub(recipBalBefore).toString(), OVER_LIMIT.toString());
+      assert.isNull(await connection.getAccountInfo(pendingPda));
+    });
+
+    it("I2. operator cannot approve its own request", async () => {
+      const pendingPda = await requestTransfer(OVER_LIMIT);
+
+      try {
+        await program.methods
+          .approvePendingTransfer()
+          .accounts({
+            signer: operator.publicKey,
+            silkAccount: accountPda,
+            pendingTransfer: pendingPda,
+            requester: operator.publicKey,
+            mint,
+            accountTokenAccount: getAta(mint, accountPda),
+            recipient: recipient.publicKey,
+            recipientTokenAccount: getAta(mint, recipient.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([operator])
+          .rpc();
+        assert.fail("Should have failed — operator approving");
+      } catch (err: any) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+
+      // Owner denies it instead; rent returns to the operator
+      await program.methods
+        .denyPendingTransfer()
+        .accounts({
+          signer: owner.publicKey,
+          silkAccount: accountPda,
+          pendingTransfer: pendingPda,
+          requester: operator.publicKey,
+        })
+        .signers([owner])
+        .rpc();
+
+      assert.isNull(await connection.getAccountInfo(pendingPda));
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group J: Yield-Only Operators (principal vs yield accounting)
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("J. Yield-Only Operators", () => {
+    const PRINCIPAL = 10_000_000; // $10
+    const YIELD = 1_000_000; // $1 — simulated by minting straight into the account ATA
+
+    let yieldOwner: Keypair;
+    let yieldOperator: Keypair;
+    let yieldPda: PublicKey;
+
+    async function operatorTransfer(amount: number) {
+      await program.methods
+        .transferFromAccount(new BN(amount))
+        .accounts({
+          signer: yieldOperator.publicKey,
+          silkAccount: yieldPda,
+          mint,
+          accountTokenAccount: getAta(mint, yieldPda),
+          recipient: recipient.publicKey,
+          recipientTokenAccount: getAta(mint, recipient.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([yieldOperator])
+        .rpc();
+    }
+
+    before(async () => {
+      yieldOwner = Keypair.generate();
+      yieldOperator = Keypair.generate();
+      for (const kp of [yieldOwner, yieldOperator]) {
+        const tx = new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: payer,
+            toPubkey: kp.publicKey,
+            lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+          })
+        );
+        await provider.sendAndConfirm(tx);
+      }
+
+      const ownerAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        mint,
+        yieldOwner.publicKey
+      );
+      await mintTo(connection, payerKeypair, mint, ownerAta, payerKeypair, PRINCIPAL);
+
+      [yieldPda] = findAccountPda(programId, yieldOwner.publicKey, mint);
+
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: yieldOwner.publicKey,
+          mint,
+          silkAccount: yieldPda,
+          accountTokenAccount: getAta(mint, yieldPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([yieldOwner])
+        .rpc();
+
+      await program.methods
+        .addOperator(yieldOperator.publicKey, null, true, OPERATOR_TRANSFER)
+        .accounts({
+          signer: yieldOwner.publicKey,
+          silkAccount: yieldPda,
+        })
+        .signers([yieldOwner])
+        .rpc();
+
+      await program.methods
+        .deposit(new BN(PRINCIPAL))
+        .accounts({
+          depositor: yieldOwner.publicKey,
+          silkAccount: yieldPda,
+          mint,
+          accountTokenAccount: getAta(mint, yieldPda),
+          depositorTokenAccount: ownerAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([yieldOwner])
+        .rpc();
+
+      await mintTo(connection, payerKeypair, mint, getAta(mint, yieldPda), payerKeypair, YIELD);
+    });
+
+    it("J1. yield-only operator cannot spend principal", async () => {
+      try {
+        await operatorTransfer(YIELD + 1);
+        assert.fail("Should have failed — exceeds accrued yield");
+      } catch (err: any) {
+        assert.include(err.toString(), "ExceedsAvailableYield");
+      }
+    });
+
+    it("J2. yield-only operator spends accrued yield; principal untouched", async () => {
+      await operatorTransfer(YIELD);
+
+      const account = await program.account.silkAccount.fetch(yieldPda);
+      assert.equal(account.principalBalance.toNumber(), PRINCIPAL);
+      assert.equal(account.yieldWithdrawn.toNumber(), YIELD);
+    });
+
+    it("J3. owner withdrawal beyond yield draws down principal", async () => {
+      await program.methods
+        .transferFromAccount(new BN(4_000_000))
+        .accounts({
+          signer: yieldOwner.publicKey,
+          silkAccount: yieldPda,
+          mint,
+          accountTokenAccount: getAta(mint, yieldPda),
+          recipient: recipient.publicKey,
+          recipientTokenAccount: getAta(mint, recipient.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([yieldOwner])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(yieldPda);
+      assert.equal(account.principalBalance.toNumber(), PRINCIPAL - 4_000_000);
+      assert.equal(account.yieldWithdrawn.toNumber(), YIELD);
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group K: Liquidity Buffer
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("K. Liquidity Buffer", () => {
+    let bufferOwner: Keypair;
+    let bufferPda: PublicKey;
+
+    before(async () => {
+      bufferOwner = Keypair.generate();
+      const tx = new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: payer,
+          toPubkey: bufferOwner.publicKey,
+          lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(tx);
+
+      [bufferPda] = findAccountPda(programId, bufferOwner.publicKey, mint);
+
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: bufferOwner.publicKey,
+          mint,
+          silkAccount: bufferPda,
+          accountTokenAccount: getAta(mint, bufferPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bufferOwner])
+        .rpc();
+    });
+
+    it("K1. rejects bps above 100% and bps combined with an absolute amount", async () => {
+      for (const [bps, amount] of [[10_001, 0], [500, 1_000_000]]) {
+        try {
+          await program.methods
+            .setLiquidityBuffer(bps, new BN(amount))
+            .accounts({
+              signer: bufferOwner.publicKey,
+              silkAccount: bufferPda,
+            })
+            .signers([bufferOwner])
+            .rpc();
+          assert.fail("Should have failed — invalid buffer");
+        } catch (err: any) {
+          assert.include(err.toString(), "InvalidLiquidityBuffer");
+        }
+      }
+    });
+
+    it("K2. owner sets a bps buffer", async () => {
+      await program.methods
+        .setLiquidityBuffer(2_000, new BN(0))
+        .accounts({
+          signer: bufferOwner.publicKey,
+          silkAccount: bufferPda,
+        })
+        .signers([bufferOwner])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(bufferPda);
+      assert.equal(account.bufferBps, 2_000);
+      assert.equal(account.bufferAmount.toNumber(), 0);
+    });
+
+    it("K3. rebalance requires an active yield adapter", async () => {
+      try {
+        await program.methods
+          .rebalance()
+          .accounts({
+            caller: payer,
+            silkAccount: bufferPda,
+            accountTokenAccount: getAta(mint, bufferPda),
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        assert.fail("Should have failed — no yield adapter");
+      } catch (err: any) {
+        assert.include(err.toString(), "NoYieldAdapter");
+      }
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group L: Account-to-Account Transfers
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("L. Account-to-Account Transfers", () => {
+    const DEPOSIT = 10_000_000; // $10
+
+    let sourceOwner: Keypair;
+    let destOwner: Keypair;
+    let sourcePda: PublicKey;
+    let destPda: PublicKey;
+
+    async function accountToAccount(signer: Keypair, amount: number) {
+      await program.methods
+        .accountToAccount(new BN(amount))
+        .accounts({
+          signer: signer.publicKey,
+          sourceAccount: sourcePda,
+          destinationAccount: destPda,
+          mint,
+          sourceTokenAccount: getAta(mint, sourcePda),
+          destinationTokenAccount: getAta(mint, destPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    before(async () => {
+      sourceOwner = Keypair.generate();
+      destOwner = Keypair.generate();
+
+      for (const kp of [sourceOwner, destOwner]) {
+        const tx = new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: payer,
+            toPubkey: kp.publicKey,
+            lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+          })
+        );
+        await provider.sendAndConfirm(tx);
+
+        const [pda] = findAccountPda(programId, kp.publicKey, mint);
+        await program.methods
+          .createAccount()
+          .accounts({
+            owner: kp.publicKey,
+            mint,
+            silkAccount: pda,
+            accountTokenAccount: getAta(mint, pda),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([kp])
+          .rpc();
+      }
+
+      [sourcePda] = findAccountPda(programId, sourceOwner.publicKey, mint);
+      [destPda] = findAccountPda(programId, destOwner.publicKey, mint);
+
+      const sourceOwnerAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        mint,
+        sourceOwner.publicKey
+      );
+      await mintTo(connection, payerKeypair, mint, sourceOwnerAta, payerKeypair, DEPOSIT);
+
+      await program.methods
+        .deposit(new BN(DEPOSIT))
+        .accounts({
+          depositor: sourceOwner.publicKey,
+          silkAccount: sourcePda,
+          mint,
+          accountTokenAccount: getAta(mint, sourcePda),
+          depositorTokenAccount: sourceOwnerAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([sourceOwner])
+        .rpc();
+    });
+
+    it("L1. owner moves funds between Silk accounts with principal bookkeeping", async () => {
+      await accountToAccount(sourceOwner, 3_000_000);
+
+      assert.equal((await getTokenBalance(connection, getAta(mint, sourcePda))).toNumber(), DEPOSIT - 3_000_000);
+      assert.equal((await getTokenBalance(connection, getAta(mint, destPda))).toNumber(), 3_000_000);
+
+      const source = await program.account.silkAccount.fetch(sourcePda);
+      const dest = await program.account.silkAccount.fetch(destPda);
+      assert.equal(source.principalBalance.toNumber(), DEPOSIT - 3_000_000);
+      assert.equal(dest.principalBalance.toNumber(), 3_000_000);
+    });
+
+    it("L2. destination owner cannot pull from the source", async () => {
+      try {
+        await accountToAccount(destOwner, 1_000_000);
+        assert.fail("Should have failed — not the source owner");
+      } catch (err: any) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group M: Recurring Payments
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("M. Recurring Payments", () => {
+    const PAYMENT = 2_000_000; // $2
+    const INTERVAL = 3600; // 1 hour
+
+    let payOwner: Keypair;
+    let payPda: PublicKey;
+    let paymentPda: PublicKey;
+    const payee = Keypair.generate();
+
+    // Schedule ids start at the account's creation slot
+    async function nextPaymentPda(): Promise<PublicKey> {
+      const acct = await program.account.silkAccount.fetch(payPda);
+      return findRecurringPaymentPda(programId, payPda, acct.nextRecurringPaymentId)[0];
+    }
+
+    async function executeRecurring() {
+      await program.methods
+        .executeRecurring()
+        .accounts({
+          caller: payer,
+          silkAccount: payPda,
+          recurringPayment: paymentPda,
+          mint,
+          accountTokenAccount: getAta(mint, payPda),
+          recipient: payee.publicKey,
+          recipientTokenAccount: getAta(mint, payee.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    const closeAccounts = () => ({
+      signer: payOwner.publicKey,
+      owner: payOwner.publicKey,
+      silkAccount: payPda,
+      mint,
+      accountTokenAccount: getAta(mint, payPda),
+      ownerTokenAccount: getAta(mint, payOwner.publicKey),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      driftCleanup: null,
+    });
+
+    before(async () => {
+      payOwner = Keypair.generate();
+      const tx = new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: payer,
+          toPubkey: payOwner.publicKey,
+          lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(tx);
+
+      [payPda] = findAccountPda(programId, payOwner.publicKey, mint);
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: payOwner.publicKey,
+          mint,
+          silkAccount: payPda,
+          accountTokenAccount: getAta(mint, payPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payOwner])
+        .rpc();
+
+      const ownerAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        mint,
+        payOwner.publicKey
+      );
+      await mintTo(connection, payerKeypair, mint, ownerAta, payerKeypair, 10_000_000);
+      await program.methods
+        .deposit(new BN(10_000_000))
+        .accounts({
+          depositor: payOwner.publicKey,
+          silkAccount: payPda,
+          mint,
+          accountTokenAccount: getAta(mint, payPda),
+          depositorTokenAccount: ownerAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payOwner])
+        .rpc();
+    });
+
+    it("M1. rejects an interval shorter than the minimum", async () => {
+      const pda = await nextPaymentPda();
+      const now = await connection.getBlockTime(await connection.getSlot());
+      try {
+        await program.methods
+          .createRecurringPayment(new BN(PAYMENT), new BN(60), new BN(now + 10), null, null)
+          .accounts({
+            signer: payOwner.publicKey,
+            silkAccount: payPda,
+            recurringPayment: pda,
+            recipient: payee.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([payOwner])
+          .rpc();
+        assert.fail("Should have failed — interval too short");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidRecurringSchedule");
+      }
+    });
+
+    it("M1b. rejects a catch-up limit of zero periods", async () => {
+      const pda = await nextPaymentPda();
+      const now = await connection.getBlockTime(await connection.getSlot());
+      try {
+        await program.methods
+          .createRecurringPayment(new BN(PAYMENT), new BN(INTERVAL), new BN(now + 10), null, 0)
+          .accounts({
+            signer: payOwner.publicKey,
+            silkAccount: payPda,
+            recurringPayment: pda,
+            recipient: payee.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([payOwner])
+          .rpc();
+        assert.fail("Should have failed — zero catch-up limit");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidRecurringSchedule");
+      }
+    });
+
+    it("M2. crank pays a due period, then waits for the next one", async () => {
+      paymentPda = await nextPaymentPda();
+      const now = await connection.getBlockTime(await connection.getSlot());
+      const firstDue = now + 1;
+
+      await program.methods
+        .createRecurringPayment(new BN(PAYMENT), new BN(INTERVAL), new BN(firstDue), null, 3)
+        .accounts({
+          signer: payOwner.publicKey,
+          silkAccount: payPda,
+          recurringPayment: paymentPda,
+          recipient: payee.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([payOwner])
+        .rpc();
+
+      await new Promise((r) => setTimeout(r, 3000));
+      await executeRecurring();
+
+      assert.equal((await getTokenBalance(connection, getAta(mint, payee.publicKey))).toNumber(), PAYMENT);
+      const payment = await program.account.recurringPayment.fetch(paymentPda);
+      assert.equal(payment.paymentsMade.toNumber(), 1);
+      assert.equal(payment.nextDue.toNumber(), firstDue + INTERVAL);
+      assert.equal(payment.maxCatchUp, 3);
+      const acct = await program.account.silkAccount.fetch(payPda);
+      assert.equal(payment.accountGeneration.toString(), acct.generation.toString());
+      assert.equal(acct.activeRecurringPayments, 1);
+
+      try {
+        await executeRecurring();
+        assert.fail("Should have failed — next period not due");
+      } catch (err: any) {
+        assert.include(err.toString(), "RecurringPaymentNotDue");
+      }
+    });
+
+    it("M2b. close_account is refused while a schedule is open", async () => {
+      try {
+        await program.methods
+          .closeAccount()
+          .accounts(closeAccounts())
+          .signers([payOwner])
+          .rpc();
+        assert.fail("Should have failed — recurring payment still open");
+      } catch (err: any) {
+        assert.include(err.toString(), "RecurringPaymentsOutstanding");
+      }
+      assert.isNotNull(await connection.getAccountInfo(paymentPda));
+    });
+
+    it("M3. owner cancels the schedule and reclaims rent", async () => {
+      await program.methods
+        .cancelRecurringPayment()
+        .accounts({
+          signer: payOwner.publicKey,
+          silkAccount: payPda,
+          recurringPayment: paymentPda,
+          rentPayer: payOwner.publicKey,
+        })
+        .signers([payOwner])
+        .rpc();
+
+      assert.isNull(await connection.getAccountInfo(paymentPda));
+      const acct = await program.account.silkAccount.fetch(payPda);
+      assert.equal(acct.activeRecurringPayments, 0);
+    });
+
+    it("M4. close_account succeeds once every schedule is cancelled", async () => {
+      await program.methods
+        .closeAccount()
+        .accounts(closeAccounts())
+        .signers([payOwner])
+        .rpc();
+
+      assert.isNull(await connection.getAccountInfo(payPda));
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group N: Operator Permissions
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("N. Operator Permissions", () => {
+    let permOwner: Keypair;
+    let permPda: PublicKey;
+    const treasuryBot = Keypair.generate();
+    const pauseBot = Keypair.generate();
+
+    before(async () => {
+      permOwner = Keypair.generate();
+      const tx = new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: payer,
+          toPubkey: permOwner.publicKey,
+          lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(tx);
+
+      [permPda] = findAccountPda(programId, permOwner.publicKey, mint);
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: permOwner.publicKey,
+          mint,
+          silkAccount: permPda,
+          accountTokenAccount: getAta(mint, permPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([permOwner])
+        .rpc();
+
+      await mintTo(connection, payerKeypair, mint, getAta(mint, permPda), payerKeypair, 5_000_000);
+    });
+
+    it("N1. rejects unknown permission bits", async () => {
+      try {
+        await program.methods
+          .addOperator(treasuryBot.publicKey, null, false, 1 << 7)
+          .accounts({
+            signer: permOwner.publicKey,
+            silkAccount: permPda,
+          })
+          .signers([permOwner])
+          .rpc();
+        assert.fail("Should have failed — unknown permission bit");
+      } catch (err: any) {
+        assert.include(err.toString(), "InvalidOperatorPermissions");
+      }
+    });
+
+    it("N2. buffer operator tunes the buffer but cannot move funds out", async () => {
+      await program.methods
+        .addOperator(treasuryBot.publicKey, null, false, OPERATOR_SET_BUFFER)
+        .accounts({
+          signer: permOwner.publicKey,
+          silkAccount: permPda,
+        })
+        .signers([permOwner])
+        .rpc();
+
+      await program.methods
+        .setLiquidityBuffer(1_000, new BN(0))
+        .accounts({
+          signer: treasuryBot.publicKey,
+          silkAccount: permPda,
+        })
+        .signers([treasuryBot])
+        .rpc();
+      const account = await program.account.silkAccount.fetch(permPda);
+      assert.equal(account.bufferBps, 1_000);
+      assert.equal(account.operators[0].permissions, OPERATOR_SET_BUFFER);
+
+      const recipient = Keypair.generate();
+      try {
+        await program.methods
+          .transferFromAccount(new BN(1_000_000))
+          .accounts({
+            signer: treasuryBot.publicKey,
+            silkAccount: permPda,
+            mint,
+            accountTokenAccount: getAta(mint, permPda),
+            recipient: recipient.publicKey,
+            recipientTokenAccount: getAta(mint, recipient.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([treasuryBot])
+          .rpc();
+        assert.fail("Should have failed — operator lacks OPERATOR_TRANSFER");
+      } catch (err: any) {
+        assert.include(err.toString(), "OperatorNotPermitted");
+      }
+
+      try {
+        await program.methods
+          .disableDrift()
+          .accounts({
+            signer: treasuryBot.publicKey,
+            silkAccount: permPda,
+            accountTokenAccount: getAta(mint, permPda),
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([treasuryBot])
+          .rpc();
+        assert.fail("Should have failed — operator lacks OPERATOR_MANAGE_VENUE");
+      } catch (err: any) {
+        assert.include(err.toString(), "OperatorNotPermitted");
+      }
+
+      try {
+        await program.methods
+          .setPause(true, null)
+          .accounts({
+            signer: treasuryBot.publicKey,
+            silkAccount: permPda,
+          })
+          .signers([treasuryBot])
+          .rpc();
+        assert.fail("Should have failed — operator lacks OPERATOR_PAUSE");
+      } catch (err: any) {
+        assert.include(err.toString(), "OperatorNotPermitted");
+      }
+    });
+
+    it("N3. pause operator may pause but not unpause", async () => {
+      await program.methods
+        .addOperator(pauseBot.publicKey, null, false, OPERATOR_PAUSE)
+        .accounts({
+          signer: permOwner.publicKey,
+          silkAccount: permPda,
+        })
+        .signers([permOwner])
+        .rpc();
+
+      await program.methods
+        .setPause(true, null)
+        .accounts({
+          signer: pauseBot.publicKey,
+          silkAccount: permPda,
+        })
+        .signers([pauseBot])
+        .rpc();
+      assert.equal((await program.account.silkAccount.fetch(permPda)).isPaused, true);
+
+      try {
+        await program.methods
+          .setPause(false, null)
+          .accounts({
+            signer: pauseBot.publicKey,
+            silkAccount: permPda,
+          })
+          .signers([pauseBot])
+          .rpc();
+        assert.fail("Should have failed — operators cannot unpause");
+      } catch (err: any) {
+        assert.include(err.toString(), "OperatorCannotUnpause");
+      }
+
+      await program.methods
+        .setPause(false, null)
+        .accounts({
+          signer: permOwner.publicKey,
+          silkAccount: permPda,
+        })
+        .signers([permOwner])
+        .rpc();
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group O: Escrow from Account
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("O. Escrow from Account", () => {
+    const handshake = anchor.workspace.handshake as Program<Handshake>;
+    const ESCROW_LIMIT = new BN(3_000_000); // $3
+
+    let escrowOwner: Keypair;
+    let escrowPda: PublicKey;
+    let poolPda: PublicKey;
+    const escrowBot = Keypair.generate();
+    const transferBot = Keypair.generate();
+    const payee = Keypair.generate();
+
+    function findEscrowPda(nonce: BN): PublicKey {
+      return PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sender"),
+          escrowPda.toBuffer(),
+          Buffer.from("recipient"),
+          payee.publicKey.toBuffer(),
+          Buffer.from("nonce"),
+          nonce.toArrayLike(Buffer, "le", 8),
+        ],
+        handshake.programId
+      )[0];
+    }
+
+    async function createEscrow(signer: Keypair, amount: BN, nonce: BN) {
+      await program.methods
+        .createEscrowFromAccount(payee.publicKey, nonce, amount, "invoice", new BN(0), new BN(0))
+        .accounts({
+          signer: signer.publicKey,
+          silkAccount: escrowPda,
+          mint,
+          accountTokenAccount: getAta(mint, escrowPda),
+          pool: poolPda,
+          poolTokenAccount: getAta(mint, poolPda),
+          transfer: findEscrowPda(nonce),
+          handshakeProgram: handshake.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    before(async () => {
+      escrowOwner = Keypair.generate();
+      const tx = new Transaction();
+      for (const kp of [escrowOwner, escrowBot]) {
+        tx.add(
+          SystemProgram.transfer({
+            fromPubkey: payer,
+            toPubkey: kp.publicKey,
+            lamports: 0.1 * web3.LAMPORTS_PER_SOL,
+          })
+        );
+      }
+      await provider.sendAndConfirm(tx);
+
+      [escrowPda] = findAccountPda(programId, escrowOwner.publicKey, mint);
+      await program.methods
+        .createAccount()
+        .accounts({
+          owner: escrowOwner.publicKey,
+          mint,
+          silkAccount: escrowPda,
+          accountTokenAccount: getAta(mint, escrowPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([escrowOwner])
+        .rpc();
+
+      const ownerAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        mint,
+        escrowOwner.publicKey
+      );
+      await mintTo(connection, payerKeypair, mint, ownerAta, payerKeypair, 10_000_000);
+      await program.methods
+        .deposit(new BN(10_000_000))
+        .accounts({
+          depositor: escrowOwner.publicKey,
+          silkAccount: escrowPda,
+          mint,
+          accountTokenAccount: getAta(mint, escrowPda),
+          depositorTokenAccount: ownerAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([escrowOwner])
+        .rpc();
+
+      for (const [bot, permissions] of [
+        [escrowBot, OPERATOR_CREATE_ESCROW],
+        [transferBot, OPERATOR_TRANSFER],
+      ] as [Keypair, number][]) {
+        await program.methods
+          .addOperator(bot.publicKey, ESCROW_LIMIT, false, permissions)
+          .accounts({
+            signer: escrowOwner.publicKey,
+            silkAccount: escrowPda,
+          })
+          .signers([escrowOwner])
+          .rpc();
+      }
+
+      const poolId = Keypair.generate().publicKey;
+      [poolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool"), poolId.toBuffer()],
+        handshake.programId
+      );
+      await handshake.methods
+        .initPool(poolId, 0)
+        .accounts({
+          operator: payer,
+          mint,
+          pool: poolPda,
+          poolTokenAccount: getAta(mint, poolPda),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+    });
+
+    it("O1. operator without OPERATOR_CREATE_ESCROW cannot fund an escrow", async () => {
+      try {
+        await createEscrow(transferBot, new BN(1_000_000), new BN(0));
+        assert.fail("Should have failed — operator lacks OPERATOR_CREATE_ESCROW");
+      } catch (err: any) {
+        assert.include(err.toString(), "OperatorNotPermitted");
+      }
+    });
+
+    it("O2. escrow operator funds an escrow with the account PDA as sender", async () => {
+      const amount = new BN(2_000_000);
+      const nonce = new BN(1);
+      await createEscrow(escrowBot, amount, nonce);
+
+      const escrow = await handshake.account.secureTransfer.fetch(findEscrowPda(nonce));
+      assert.equal(escrow.sender.toString(), escrowPda.toString());
+      assert.equal(escrow.recipient.toString(), payee.publicKey.toString());
+      assert.equal(escrow.amount.toNumber(), amount.toNumber());
+      assert.equal(escrow.rentTo.toString(), escrowBot.publicKey.toString());
+
+      const pool = await handshake.account.pool.fetch(poolPda);
+      assert.equal(pool.totalEscrowed.toNumber(), amount.toNumber());
+
+      const account = await program.account.silkAccount.fetch(escrowPda);
+      assert.equal(account.principalBalance.toNumber(), 8_000_000);
+      assert.equal(
+        (await getTokenBalance(connection, getAta(mint, escrowPda))).toNumber(),
+        8_000_000
+      );
+    });
+
+    it("O3. escrow amount is bound by the operator's per-tx limit", async () => {
+      try {
+        await createEscrow(escrowBot, ESCROW_LIMIT.addn(1), new BN(2));
+        assert.fail("Should have failed — above per-tx limit");
+      } catch (err: any) {
+        assert.include(err.toString(), "ExceedsPerTxLimit");
+      }
+    });
+
+    it("O4. escrow operator cancels the escrow and the refund is booked as principal", async () => {
+      const transfer = findEscrowPda(new BN(1));
+      const cancelAccounts = (signer: Keypair) => ({
+        signer: signer.publicKey,
+        silkAccount: escrowPda,
+        mint,
+        accountTokenAccount: getAta(mint, escrowPda),
+        pool: poolPda,
+        poolTokenAccount: getAta(mint, poolPda),
+        transfer,
+        rentTo: escrowBot.publicKey,
+        handshakeProgram: handshake.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        poolMint: null,
+      });
+
+      try {
+        await program.methods
+          .cancelEscrowFromAccount()
+          .accounts(cancelAccounts(transferBot))
+          .signers([transferBot])
+          .rpc();
+        assert.fail("Should have failed — operator lacks OPERATOR_CREATE_ESCROW");
+      } catch (err: any) {
+        assert.include(err.toString(), "OperatorNotPermitted");
+      }
+
+      const botLamportsBefore = await connection.getBalance(escrowBot.publicKey);
+      const escrowRent = await connection.getBalance(transfer);
+
+      await program.methods
+        .cancelEscrowFromAccount()
+        .accounts(cancelAccounts(escrowBot))
+        .signers([escrowBot])
+        .rpc();
+
+      assert.isNull(await connection.getAccountInfo(transfer), "Escrow should be closed");
+      const account = await program.account.silkAccount.fetch(escrowPda);
+      assert.equal(account.principalBalance.toNumber(), 10_000_000);
+      assert.equal(
+        (await getTokenBalance(connection, getAta(mint, escrowPda))).toNumber(),
+        10_000_000
+      );
+      const pool = await handshake.account.pool.fetch(poolPda);
+      assert.equal(pool.totalEscrowed.toNumber(), 0);
+
+      // escrowBot paid the transaction fee, so it gets back the rent less that fee
+      const botLamportsAfter = await connection.getBalance(escrowBot.publicKey);
+      assert.isAbove(botLamportsAfter, botLamportsBefore + escrowRent - 10_000);
+    });
+  });
+
+  // ═══════════════════════════════════════════════════════════════════════════
+  // Group P: Token-2022 Mints
+  // ═══════════════════════════════════════════════════════════════════════════
+
+  describe("P. Token-2022 Mints", () => {
+    const FEE_BPS = 100; // 1%
+
+    async function createToken2022Mint(
+      extensions: ExtensionType[],
+      initExtensions: (mint: PublicKey) => web3.TransactionInstruction[]
+    ): Promise<PublicKey> {
+      const mintKeypair = Keypair.generate();
+      const space = getMintLen(extensions);
+      const tx = new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: payer,
+          newAccountPubkey: mintKeypair.publicKey,
+          space,
+          lamports: await connection.getMinimumBalanceForRentExemption(space),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        ...initExtensions(mintKeypair.publicKey),
+        createInitializeMintInstruction(
+          mintKeypair.publicKey,
+          6,
+          payer,
+          null,
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+      await provider.sendAndConfirm(tx, [mintKeypair]);
+      return mintKeypair.publicKey;
+    }
+
+    function createAccountAccounts(token2022Mint: PublicKey, silkAccount: PublicKey) {
+      return {
+        owner: owner.publicKey,
+        mint: token2022Mint,
+        silkAccount,
+        accountTokenAccount: getAta(token2022Mint, silkAccount, TOKEN_2022_PROGRAM_ID),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+    }
+
+    it("P1. deposit books what the account receives from a transfer-fee mint", async () => {
+      const feeMint = await createToken2022Mint([ExtensionType.TransferFeeConfig], (mint) => [
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          payer,
+          payer,
+          FEE_BPS,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+      ]);
+
+      const [feeAccountPda] = findAccountPda(programId, owner.publicKey, feeMint);
+      await program.methods
+        .createAccount()
+        .accounts(createAccountAccounts(feeMint, feeAccountPda))
+        .signers([owner])
+        .rpc();
+
+      const ownerAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        feeMint,
+        owner.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(connection, payerKeypair, feeMint, ownerAta, payerKeypair, 10_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+      const amount = new BN(1_000_000);
+      const expectedFee = amount.muln(FEE_BPS).divn(10_000);
+      await program.methods
+        .deposit(amount)
+        .accounts({
+          depositor: owner.publicKey,
+          silkAccount: feeAccountPda,
+          mint: feeMint,
+          accountTokenAccount: getAta(feeMint, feeAccountPda, TOKEN_2022_PROGRAM_ID),
+          depositorTokenAccount: ownerAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([owner])
+        .rpc();
+
+      const account = await program.account.silkAccount.fetch(feeAccountPda);
+      assert.equal(account.principalBalance.toString(), amount.sub(expectedFee).toString());
+    });
+
+    it("P2. rejects an account for a non-transferable mint", async () => {
+      const soulboundMint = await createToken2022Mint([ExtensionType.NonTransferable], (mint) => [
+        createInitializeNonTransferableMintInstruction(mint, TOKEN_2022_PROGRAM_ID),
+      ]);
+
+      const [soulboundPda] = findAccountPda(programId, owner.publicKey, soulboundMint);
+      try {
+        await program.methods
+          .createAccount()
+          .accounts(createAccountAccounts(soulboundMint, soulboundPda))
+          .signers([owner])
+          .rpc();
+        assert.fail("Should have failed — non-transferable mint");
+      } catch (err: any) {
+        assert.include(err.toString(), "UnsupportedMintExtension");
+      }
+    });
+
+    it("P3. transfer_from_account forwards a transfer hook's extra accounts", async () => {
+      const hookProgram = anchor.workspace.transferHookCounter as Program<TransferHookCounter>;
+      const hookMint = await createToken2022Mint([ExtensionType.TransferHook], (mint) => [
+        createInitializeTransferHookInstruction(mint, payer, hookProgram.programId, TOKEN_2022_PROGRAM_ID),
+      ]);
+
+      const extraAccountMetas = getExtraAccountMetaAddress(hookMint, hookProgram.programId);
+      const [counter] = PublicKey.findProgramAddressSync(
+        [Buffer.from("counter"), hookMint.toBuffer()],
+        hookProgram.programId
+      );
+      await hookProgram.methods
+        .initializeExtraAccountMetaList()
+        .accounts({
+          payer,
+          extraAccountMetaList: extraAccountMetas,
+          mint: hookMint,
+          counter,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      const hookAccounts = [
+        { pubkey: hookProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: extraAccountMetas, isSigner: false, isWritable: false },
+        { pubkey: counter, isSigner: false, isWritable: true },
+      ];
+
+      const [hookAccountPda] = findAccountPda(programId, owner.publicKey, hookMint);
+      await program.methods
+        .createAccount()
+        .accounts(createAccountAccounts(hookMint, hookAccountPda))
+        .signers([owner])
+        .rpc();
+
+      const ownerAta = await createAssociatedTokenAccount(
+        connection,
+        payerKeypair,
+        hookMint,
+        owner.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(connection, payerKeypair, hookMint, ownerAta, payerKeypair, 10_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+      await program.methods
+        .deposit(new BN(5_000_000))
+        .accounts({
+          depositor: owner.publicKey,
+          silkAccount: hookAccountPda,
+          mint: hookMint,
+          accountTokenAccount: getAta(hookMint, hookAccountPda, TOKEN_2022_PROGRAM_ID),
+          depositorTokenAccount: ownerAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(hookAccounts)
+        .signers([owner])
+        .rpc();
+
+      const amount = new BN(2_000_000);
+      const recipientAta = getAta(hookMint, recipient.publicKey, TOKEN_2022_PROGRAM_ID);
+      const transferAccounts = {
+        signer: owner.publicKey,
+        silkAccount: hookAccountPda,
+        mint: hookMint,
+        accountTokenAccount: getAta(hookMint, hookAccountPda, TOKEN_2022_PROGRAM_ID),
+        recipient: recipient.publicKey,
+        recipientTokenAccount: recipientAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+
+      await program.methods
+        .transferFromAccount(amount)
+        .accounts(transferAccounts)
+        .remainingAccounts(hookAccounts)
+        .signers([owner])
+        .rpc();
+
+      const recipientBalance = await connection.getTokenAccountBalance(recipientAta);
+      assert.equal(recipientBalance.value.amount, amount.toString());
+      // One hooked transfer for the deposit, one for the payout
+      assert.equal((await hookProgram.account.transferCounter.fetch(counter)).transfers.toNumber(), 2);
     });
   });
 });
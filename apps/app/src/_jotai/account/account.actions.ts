@@ -8,7 +8,14 @@ export function useAccountActions() {
   const { signTransaction } = useWallet();
 
   const createAccount = useCallback(
-    async (params: { owner: string; mint: string; operator?: string; perTxLimit?: number }) => {
+    async (params: {
+      owner: string;
+      mint: string;
+      operator?: string;
+      perTxLimit?: number;
+      yieldOnly?: boolean;
+      permissions?: number;
+    }) => {
       const res = await api.post('/api/account/create', params);
       return res.data.data as { transaction: string; accountPda: string };
     },
@@ -49,8 +56,8 @@ export function useAccountActions() {
     [signTransaction],
   );
 
-  const togglePause = useCallback(
-    async (params: { owner: string; accountPda: string }) => {
+  const setPause = useCallback(
+    async (params: { owner: string; accountPda: string; isPaused: boolean; reason?: number }) => {
       const res = await api.post('/api/account/pause', params);
       return res.data.data as { transaction: string };
     },
@@ -58,7 +65,14 @@ export function useAccountActions() {
   );
 
   const addOperator = useCallback(
-    async (params: { owner: string; accountPda: string; operator: string; perTxLimit?: number }) => {
+    async (params: {
+      owner: string;
+      accountPda: string;
+      operator: string;
+      perTxLimit?: number;
+      yieldOnly?: boolean;
+      permissions?: number;
+    }) => {
       const res = await api.post('/api/account/add-operator', params);
       return res.data.data as { transaction: string };
     },
@@ -118,7 +132,7 @@ export function useAccountActions() {
     transferFromAccount,
     fetchAccount,
     signAndSubmit,
-    togglePause,
+    setPause,
     addOperator,
     removeOperator,
     closeAccount,
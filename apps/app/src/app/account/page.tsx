@@ -49,7 +49,7 @@ export default function AccountDashboardPage() {
     fetchAccount,
     depositToAccount,
     transferFromAccount,
-    togglePause,
+    setPause,
     addOperator,
     removeOperator,
     closeAccount,
@@ -273,7 +273,11 @@ export default function AccountDashboardPage() {
     if (!account) return;
     setPauseLoading(true);
     try {
-      const { transaction } = await togglePause({ owner: walletAddress, accountPda: account.pda });
+      const { transaction } = await setPause({
+        owner: walletAddress,
+        accountPda: account.pda,
+        isPaused: !account.isPaused,
+      });
       toast.info('Please approve the transaction in your wallet...');
       await signAndSubmit(transaction);
       toast.success(account.isPaused ? 'Account resumed' : 'Account paused');
@@ -12,6 +12,7 @@ import {
 } from '@nestjs/common';
 import { PublicKey } from '@solana/web3.js';
 import { AccountService } from '../service/account.service';
+import { OPERATOR_ALL_PERMISSIONS } from '../../solana/silkysig-client';
 import { SilkAccountEventType } from '../../db/models/SilkAccountEvent';
 
 @Controller('api/account')
@@ -149,7 +150,7 @@ export class AccountController {
   @Post('create')
   @HttpCode(200)
   async createAccount(
-    @Body() body: { owner: string; mint: string; operator?: string; perTxLimit?: number },
+    @Body() body: { owner: string; mint: string; operator?: string; perTxLimit?: number; yieldOnly?: boolean; permissions?: number },
   ) {
     this.validatePubkey(body.owner, 'owner');
     this.validatePubkey(body.mint, 'mint');
@@ -157,6 +158,7 @@ export class AccountController {
     if (body.perTxLimit != null && body.perTxLimit < 0) {
       throw new BadRequestException({ ok: false, error: 'INVALID_LIMIT', message: 'perTxLimit must be >= 0' });
     }
+    this.validateOperatorPolicy(body);
 
     const data = await this.accountService.buildCreateAccountTx(body);
     return { ok: true, data };
@@ -195,18 +197,26 @@ export class AccountController {
 
   @Post('pause')
   @HttpCode(200)
-  async togglePause(@Body() body: { owner: string; accountPda: string }) {
+  async setPause(
+    @Body() body: { owner: string; accountPda: string; isPaused: boolean; reason?: number },
+  ) {
     this.validatePubkey(body.owner, 'owner');
     this.validatePubkey(body.accountPda, 'accountPda');
+    if (typeof body.isPaused !== 'boolean') {
+      throw new BadRequestException({ ok: false, error: 'INVALID_PAUSE_STATE', message: 'isPaused must be a boolean' });
+    }
+    if (body.reason != null && (!Number.isInteger(body.reason) || body.reason < 0 || body.reason > 255)) {
+      throw new BadRequestException({ ok: false, error: 'INVALID_REASON', message: 'reason must be an integer between 0 and 255' });
+    }
 
-    const data = await this.accountService.buildTogglePauseTx(body);
+    const data = await this.accountService.buildSetPauseTx(body);
     return { ok: true, data };
   }
 
   @Post('add-operator')
   @HttpCode(200)
   async addOperator(
-    @Body() body: { owner: string; accountPda: string; operator: string; perTxLimit?: number },
+    @Body() body: { owner: string; accountPda: string; operator: string; perTxLimit?: number; yieldOnly?: boolean; permissions?: number },
   ) {
     this.validatePubkey(body.owner, 'owner');
     this.validatePubkey(body.accountPda, 'accountPda');
@@ -214,6 +224,7 @@ export class AccountController {
     if (body.perTxLimit != null && body.perTxLimit < 0) {
       throw new BadRequestException({ ok: false, error: 'INVALID_LIMIT', message: 'perTxLimit must be >= 0' });
     }
+    this.validateOperatorPolicy(body);
 
     const data = await this.accountService.buildAddOperatorTx(body);
     return { ok: true, data };
@@ -252,4 +263,16 @@ export class AccountController {
       throw new BadRequestException({ ok: false, error: 'INVALID_PUBKEY', message: `${field} is not a valid public key` });
     }
   }
+
+  private validateOperatorPolicy(body: { yieldOnly?: boolean; permissions?: number }) {
+    if (body.yieldOnly != null && typeof body.yieldOnly !== 'boolean') {
+      throw new BadRequestException({ ok: false, error: 'INVALID_YIELD_ONLY', message: 'yieldOnly must be a boolean' });
+    }
+    if (
+      body.permissions != null &&
+      (!Number.isInteger(body.permissions) || body.permissions < 0 || (body.permissions & ~OPERATOR_ALL_PERMISSIONS) !== 0)
+    ) {
+      throw new BadRequestException({ ok: false, error: 'INVALID_PERMISSIONS', message: 'permissions has unknown operator permission bits' });
+    }
+  }
 }
@@ -15,7 +15,7 @@ const SILKYSIG_INSTRUCTION_MAP: Record<string, SilkAccountEventType> = {
   TransferFromAccount: SilkAccountEventType.TRANSFER,
   AddOperator: SilkAccountEventType.OPERATOR_ADDED,
   RemoveOperator: SilkAccountEventType.OPERATOR_REMOVED,
-  TogglePause: SilkAccountEventType.PAUSED, // resolved to PAUSED/UNPAUSED later
+  SetPause: SilkAccountEventType.PAUSED, // resolved to PAUSED/UNPAUSED later
 };
 
 @Injectable()
@@ -75,15 +75,17 @@ export class AccountService {
     mint: string;
     operator?: string;
     perTxLimit?: number;
+    yieldOnly?: boolean;
+    permissions?: number;
   }) {
-    this.logger.log(`buildCreateAccountTx: owner=${params.owner}, operator=${params.operator || 'none'}, limit=${params.perTxLimit ?? 'none'}`);
+    this.logger.log(`buildCreateAccountTx: owner=${params.owner}, operator=${params.operator || 'none'}, limit=${params.perTxLimit ?? 'none'}, permissions=${params.permissions ?? 'default'}`);
     const client = this.solanaService.getSilkysigClient();
     const owner = new PublicKey(params.owner);
     const mint = new PublicKey(params.mint);
     const operator = params.operator ? new PublicKey(params.operator) : undefined;
     const perTxLimit = params.perTxLimit != null ? new BN(params.perTxLimit) : undefined;
 
-    return client.buildCreateAccountTx(owner, mint, operator, perTxLimit);
+    return client.buildCreateAccountTx(owner, mint, operator, perTxLimit, params.yieldOnly, params.permissions);
   }
 
   async buildDepositTx(params: {
@@ -116,11 +118,13 @@ export class AccountService {
     );
   }
 
-  async buildTogglePauseTx(params: { owner: string; accountPda: string }) {
+  async buildSetPauseTx(params: { owner: string; accountPda: string; isPaused: boolean; reason?: number }) {
     const client = this.solanaService.getSilkysigClient();
-    return client.buildTogglePauseTx(
+    return client.buildSetPauseTx(
       new PublicKey(params.owner),
       new PublicKey(params.accountPda),
+      params.isPaused,
+      params.reason,
     );
   }
 
@@ -129,6 +133,8 @@ export class AccountService {
     accountPda: string;
     operator: string;
     perTxLimit?: number;
+    yieldOnly?: boolean;
+    permissions?: number;
   }) {
     const client = this.solanaService.getSilkysigClient();
     return client.buildAddOperatorTx(
@@ -136,6 +142,8 @@ export class AccountService {
       new PublicKey(params.accountPda),
       new PublicKey(params.operator),
       params.perTxLimit != null ? new BN(params.perTxLimit) : undefined,
+      params.yieldOnly,
+      params.permissions,
     );
   }
 
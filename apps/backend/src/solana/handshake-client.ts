@@ -38,6 +38,18 @@ export interface PoolAccount {
   isPaused: boolean;
 }
 
+export interface PoolMintAccount {
+  version: number;
+  bump: number;
+  pool: PublicKey;
+  mint: PublicKey;
+  transferFeeBps: number;
+  totalDeposits: BN;
+  totalWithdrawals: BN;
+  totalEscrowed: BN;
+  collectedFees: BN;
+}
+
 export interface TransferAccount {
   version: number;
   bump: number;
@@ -53,6 +65,8 @@ export interface TransferAccount {
   releaseConditions: any;
   memo: number[];
   complianceHash: number[] | null;
+  refundTo: PublicKey;
+  rentTo: PublicKey;
 }
 
 export class HandshakeClient {
@@ -161,6 +175,9 @@ export class HandshakeClient {
       tokenProgram,
       systemProgram: SystemProgram.programId,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      refundTo: null,
+      rentTo: null,
+      poolMint: null,
     };
 
     const ix = await (this.program.methods as any)
@@ -180,10 +197,7 @@ export class HandshakeClient {
     if (!transferAccount) {
       throw new Error(`Transfer account not found: ${transferPda.toBase58()}. It may have already been claimed or cancelled.`);
     }
-    const poolPda = transferAccount.pool;
-    const poolAccount = (await (this.program.account as any).pool.fetch(poolPda)) as PoolAccount;
-    const mint = poolAccount.mint;
-    const sender = transferAccount.sender;
+    const { poolPda, poolMint, mint } = await this.resolveTransferPool(transferAccount);
 
     const tokenProgram = isToken2022 ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
     const poolTokenAccount = this.getTokenAccount(mint, poolPda, isToken2022);
@@ -196,8 +210,9 @@ export class HandshakeClient {
       poolTokenAccount,
       recipientTokenAccount,
       transfer: transferPda,
-      sender,
+      rentTo: this.rentRecipient(transferAccount),
       tokenProgram,
+      poolMint,
     };
 
     const ix = await (this.program.methods as any)
@@ -217,22 +232,25 @@ export class HandshakeClient {
     if (!transferAccount) {
       throw new Error(`Transfer account not found: ${transferPda.toBase58()}. It may have already been claimed or cancelled.`);
     }
-    const poolPda = transferAccount.pool;
-    const poolAccount = (await (this.program.account as any).pool.fetch(poolPda)) as PoolAccount;
-    const mint = poolAccount.mint;
+    const { poolPda, poolMint, mint } = await this.resolveTransferPool(transferAccount);
 
     const tokenProgram = isToken2022 ? TOKEN_2022_PROGRAM_ID : TOKEN_PROGRAM_ID;
     const poolTokenAccount = this.getTokenAccount(mint, poolPda, isToken2022);
-    const senderTokenAccount = this.getTokenAccount(mint, sender, isToken2022);
+    // A zero refund_to predates the field: any sender account is accepted, so use the ATA
+    const refundTo = transferAccount.refundTo.equals(PublicKey.default)
+      ? this.getTokenAccount(mint, sender, isToken2022)
+      : transferAccount.refundTo;
 
     const accounts = {
       sender,
       pool: poolPda,
       mint,
       poolTokenAccount,
-      senderTokenAccount,
+      refundTo,
       transfer: transferPda,
+      rentTo: this.rentRecipient(transferAccount),
       tokenProgram,
+      poolMint,
     };
 
     const ix = await (this.program.methods as any)
@@ -243,6 +261,29 @@ export class HandshakeClient {
     return { ix };
   }
 
+  /**
+   * Parent pool, PoolMint child (or null) and mint behind a transfer. A transfer in
+   * a mint added with `add_pool_mint` records the child as its `pool`.
+   */
+  async resolveTransferPool(
+    transfer: TransferAccount,
+  ): Promise<{ poolPda: PublicKey; poolMint: PublicKey | null; mint: PublicKey }> {
+    const poolMintAccount = (await (this.program.account as any).poolMint.fetchNullable(
+      transfer.pool,
+    )) as PoolMintAccount | null;
+    if (poolMintAccount) {
+      return { poolPda: poolMintAccount.pool, poolMint: transfer.pool, mint: poolMintAccount.mint };
+    }
+
+    const poolAccount = (await (this.program.account as any).pool.fetch(transfer.pool)) as PoolAccount;
+    return { poolPda: transfer.pool, poolMint: null, mint: poolAccount.mint };
+  }
+
+  /** Account that receives the transfer's rent on close (mirrors `SecureTransfer::rent_recipient`) */
+  rentRecipient(transfer: TransferAccount): PublicKey {
+    return transfer.rentTo.equals(PublicKey.default) ? transfer.sender : transfer.rentTo;
+  }
+
   async fetchPool(poolPda: PublicKey): Promise<PoolAccount | null> {
     try {
       return (await (this.program.account as any).pool.fetch(poolPda)) as PoolAccount;
@@ -9,6 +9,16 @@ import {
 } from '@solana/spl-token';
 
 export const ACCOUNT_SEED = 'account';
+export const DRIFT_CLEANUP_SEED = 'drift_cleanup';
+
+// Operator permission bits (mirror OPERATOR_* in state/account.rs)
+export const OPERATOR_TRANSFER = 1 << 0;
+export const OPERATOR_SET_BUFFER = 1 << 1;
+export const OPERATOR_CREATE_ESCROW = 1 << 2;
+export const OPERATOR_PAUSE = 1 << 3;
+export const OPERATOR_MANAGE_VENUE = 1 << 4;
+export const OPERATOR_ALL_PERMISSIONS =
+  OPERATOR_TRANSFER | OPERATOR_SET_BUFFER | OPERATOR_CREATE_ESCROW | OPERATOR_PAUSE | OPERATOR_MANAGE_VENUE;
 
 const DRIFT_PROGRAM = new PublicKey('dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH');
 const USDC_MARKET_INDEX = 0;
@@ -16,12 +26,15 @@ const USDC_MARKET_INDEX = 0;
 export interface OperatorSlotData {
   pubkey: PublicKey;
   perTxLimit: BN;
+  yieldOnly: boolean;
+  permissions: number;
 }
 
 export interface SilkAccountData {
   version: number;
   bump: number;
   owner: PublicKey;
+  creator: PublicKey;
   mint: PublicKey;
   isPaused: boolean;
   operatorCount: number;
@@ -40,13 +53,20 @@ export class SilkysigClient {
     this.connection = program.provider.connection;
   }
 
-  findAccountPda(owner: PublicKey): [PublicKey, number] {
+  findAccountPda(owner: PublicKey, mint: PublicKey): [PublicKey, number] {
     return web3.PublicKey.findProgramAddressSync(
-      [Buffer.from(ACCOUNT_SEED), owner.toBuffer()],
+      [Buffer.from(ACCOUNT_SEED), owner.toBuffer(), mint.toBuffer()],
       this.program.programId,
     );
   }
 
+  findDriftCleanupPda(accountPda: PublicKey): PublicKey {
+    return web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(DRIFT_CLEANUP_SEED), accountPda.toBuffer()],
+      this.program.programId,
+    )[0];
+  }
+
   async fetchAccount(pda: PublicKey): Promise<SilkAccountData | null> {
     try {
       return (await (this.program.account as any).silkAccount.fetch(pda)) as SilkAccountData;
@@ -62,9 +82,9 @@ export class SilkysigClient {
     const operatorBytes = operatorPubkey.toBuffer();
 
     // Operator pubkey offsets in account data:
-    // Discriminator: 8, version: 1, bump: 1, owner: 32, mint: 32, is_paused: 1, operator_count: 1 = 76 bytes
-    // Each OperatorSlot: pubkey(32) + per_tx_limit(8) + _reserved(24) = 64 bytes
-    const baseOffset = 76;
+    // Discriminator: 8, version: 1, bump: 1, owner: 32, creator: 32, mint: 32, is_paused: 1, operator_count: 1 = 108 bytes
+    // Each OperatorSlot: pubkey(32) + per_tx_limit(8) + yield_only(1) + permissions(1) + _reserved(22) = 64 bytes
+    const baseOffset = 108;
     const slotSize = 64;
     const offsets = [baseOffset, baseOffset + slotSize, baseOffset + slotSize * 2];
 
@@ -123,8 +143,10 @@ export class SilkysigClient {
     mint: PublicKey,
     operator?: PublicKey,
     perTxLimit?: BN,
+    yieldOnly = false,
+    permissions = OPERATOR_TRANSFER,
   ): Promise<{ transaction: string; accountPda: string }> {
-    const [silkAccountPda] = this.findAccountPda(owner);
+    const [silkAccountPda] = this.findAccountPda(owner, mint);
     const accountTokenAccount = getAssociatedTokenAddressSync(mint, silkAccountPda, true);
 
     const createIx = await (this.program.methods as any)
@@ -148,50 +170,46 @@ export class SilkysigClient {
 
     if (operator) {
       const addOpIx = await (this.program.methods as any)
-        .addOperator(operator, perTxLimit ?? null)
+        .addOperator(operator, perTxLimit ?? null, yieldOnly, permissions)
         .accounts({
-          owner,
+          signer: owner,
           silkAccount: silkAccountPda,
         })
         .instruction();
       tx.add(addOpIx);
     }
 
-    // Initialize Drift so deposits earn yield — skip if Drift accounts already exist
-    // (e.g. owner closed and is recreating their account)
+    // Initialize Drift so deposits earn yield — Drift accounts left by a previously
+    // closed account (same owner and mint) are reused by init_drift_user
     const subAccountId = 0;
+    const name = Array.from(Buffer.alloc(32));
+    const driftUser = this.getDriftUserPDA(silkAccountPda, subAccountId);
     const driftUserStats = this.getDriftUserStatsPDA(silkAccountPda);
-    const driftUserStatsInfo = await this.connection.getAccountInfo(driftUserStats);
-
-    if (!driftUserStatsInfo) {
-      const name = Array.from(Buffer.alloc(32));
-      const driftUser = this.getDriftUserPDA(silkAccountPda, subAccountId);
-      const driftState = this.getDriftStatePDA();
-      const driftSpotMarketVault = this.getDriftSpotMarketVaultPDA(USDC_MARKET_INDEX);
-      const driftSpotMarket = this.getDriftSpotMarketPDA(USDC_MARKET_INDEX);
-      const driftOracle = await this.fetchSpotMarketOracle(USDC_MARKET_INDEX);
-
-      const initDriftIx = await (this.program.methods as any)
-        .initDriftUser(subAccountId, name, USDC_MARKET_INDEX)
-        .accounts({
-          owner,
-          silkAccount: silkAccountPda,
-          mint,
-          accountTokenAccount,
-          driftUser,
-          driftUserStats,
-          driftState,
-          driftSpotMarketVault,
-          driftSpotMarket,
-          driftOracle,
-          driftProgram: DRIFT_PROGRAM,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          rent: web3.SYSVAR_RENT_PUBKEY,
-          systemProgram: SystemProgram.programId,
-        })
-        .instruction();
-      tx.add(initDriftIx);
-    }
+    const driftState = this.getDriftStatePDA();
+    const driftSpotMarketVault = this.getDriftSpotMarketVaultPDA(USDC_MARKET_INDEX);
+    const driftSpotMarket = this.getDriftSpotMarketPDA(USDC_MARKET_INDEX);
+    const driftOracle = await this.fetchSpotMarketOracle(USDC_MARKET_INDEX);
+
+    const initDriftIx = await (this.program.methods as any)
+      .initDriftUser(subAccountId, name, USDC_MARKET_INDEX)
+      .accounts({
+        signer: owner,
+        silkAccount: silkAccountPda,
+        mint,
+        accountTokenAccount,
+        driftUser,
+        driftUserStats,
+        driftState,
+        driftSpotMarketVault,
+        driftSpotMarket,
+        driftOracle,
+        driftProgram: DRIFT_PROGRAM,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: web3.SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    tx.add(initDriftIx);
 
     const serialized = tx.serialize({ requireAllSignatures: false }).toString('base64');
     return { transaction: serialized, accountPda: silkAccountPda.toBase58() };
@@ -220,9 +238,9 @@ export class SilkysigClient {
         tokenProgram: TOKEN_PROGRAM_ID,
       });
 
-    // If Drift is initialized, add remaining accounts for Drift deposit
+    // If Drift is initialized, add the shared Drift account set for the deposit CPI
     if (account.driftUser) {
-      const driftAccounts = await this.buildDriftDepositRemainingAccounts(account, accountPda);
+      const driftAccounts = await this.buildDriftRemainingAccounts(account, accountPda);
       builder.remainingAccounts(driftAccounts);
     }
 
@@ -267,7 +285,7 @@ export class SilkysigClient {
 
     // If Drift is initialized, add remaining accounts for Drift withdraw
     if (account.driftUser) {
-      const driftAccounts = await this.buildDriftWithdrawRemainingAccounts(account, accountPda);
+      const driftAccounts = await this.buildDriftRemainingAccounts(account, accountPda);
       builder.remainingAccounts(driftAccounts);
     }
 
@@ -283,14 +301,16 @@ export class SilkysigClient {
     return { transaction: serialized };
   }
 
-  async buildTogglePauseTx(
-    owner: PublicKey,
+  async buildSetPauseTx(
+    signer: PublicKey,
     accountPda: PublicKey,
+    isPaused: boolean,
+    reason?: number,
   ): Promise<{ transaction: string }> {
     const ix = await (this.program.methods as any)
-      .togglePause()
+      .setPause(isPaused, reason ?? null)
       .accounts({
-        owner,
+        signer,
         silkAccount: accountPda,
       })
       .instruction();
@@ -298,7 +318,7 @@ export class SilkysigClient {
     const { blockhash } = await this.connection.getLatestBlockhash('confirmed');
     const tx = new Transaction();
     tx.recentBlockhash = blockhash;
-    tx.feePayer = owner;
+    tx.feePayer = signer;
     tx.add(ix);
 
     const serialized = tx.serialize({ requireAllSignatures: false }).toString('base64');
@@ -310,11 +330,13 @@ export class SilkysigClient {
     accountPda: PublicKey,
     operator: PublicKey,
     perTxLimit?: BN,
+    yieldOnly = false,
+    permissions = OPERATOR_TRANSFER,
   ): Promise<{ transaction: string }> {
     const ix = await (this.program.methods as any)
-      .addOperator(operator, perTxLimit ?? null)
+      .addOperator(operator, perTxLimit ?? null, yieldOnly, permissions)
       .accounts({
-        owner,
+        signer: owner,
         silkAccount: accountPda,
       })
       .instruction();
@@ -337,7 +359,7 @@ export class SilkysigClient {
     const ix = await (this.program.methods as any)
       .removeOperator(operator)
       .accounts({
-        owner,
+        signer: owner,
         silkAccount: accountPda,
       })
       .instruction();
@@ -374,11 +396,13 @@ export class SilkysigClient {
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        // Records the closing owner so the orphaned Drift user's rent can be reclaimed later
+        driftCleanup: account.driftUser ? this.findDriftCleanupPda(accountPda) : null,
       });
 
     // If Drift is initialized, add remaining accounts for Drift withdraw
     if (account.driftUser) {
-      const driftAccounts = await this.buildDriftWithdrawRemainingAccounts(account, accountPda);
+      const driftAccounts = await this.buildDriftRemainingAccounts(account, accountPda);
       builder.remainingAccounts(driftAccounts);
     }
 
@@ -447,33 +471,16 @@ export class SilkysigClient {
     return new PublicKey(oracleBytes);
   }
 
-  private async buildDriftDepositRemainingAccounts(
-    account: SilkAccountData,
-    accountPda: PublicKey,
-  ): Promise<Array<{ pubkey: PublicKey; isSigner: boolean; isWritable: boolean }>> {
-    const marketIndex = account.driftMarketIndex!;
-    const oracle = await this.fetchSpotMarketOracle(marketIndex);
-
-    // Layout: drift_state, drift_user, drift_user_stats, drift_spot_market_vault, drift_program, drift_oracle, drift_spot_market
-    return [
-      { pubkey: this.getDriftStatePDA(), isSigner: false, isWritable: true },
-      { pubkey: account.driftUser!, isSigner: false, isWritable: true },
-      { pubkey: this.getDriftUserStatsPDA(accountPda), isSigner: false, isWritable: true },
-      { pubkey: this.getDriftSpotMarketVaultPDA(marketIndex), isSigner: false, isWritable: true },
-      { pubkey: DRIFT_PROGRAM, isSigner: false, isWritable: false },
-      { pubkey: oracle, isSigner: false, isWritable: false },
-      { pubkey: this.getDriftSpotMarketPDA(marketIndex), isSigner: false, isWritable: true },
-    ];
-  }
-
-  private async buildDriftWithdrawRemainingAccounts(
+  // Drift account set every yield instruction reads from the start of its remaining accounts,
+  // in the order `DriftAccounts::load` expects:
+  // drift_state, drift_user, drift_user_stats, drift_spot_market_vault, drift_signer, drift_program, drift_oracle, drift_spot_market
+  private async buildDriftRemainingAccounts(
     account: SilkAccountData,
     accountPda: PublicKey,
   ): Promise<Array<{ pubkey: PublicKey; isSigner: boolean; isWritable: boolean }>> {
     const marketIndex = account.driftMarketIndex!;
     const oracle = await this.fetchSpotMarketOracle(marketIndex);
 
-    // Layout: drift_state, drift_user, drift_user_stats, drift_spot_market_vault, drift_signer, drift_program, drift_oracle, drift_spot_market
     return [
       { pubkey: this.getDriftStatePDA(), isSigner: false, isWritable: true },
       { pubkey: account.driftUser!, isSigner: false, isWritable: true },